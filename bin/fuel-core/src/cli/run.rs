@@ -24,7 +24,11 @@ use fuel_core::{
     producer::Config as ProducerConfig,
     service::{
         config::Trigger,
-        genesis::NotifyCancel,
+        genesis::{
+            GenesisImportConfig,
+            NotifyCancel,
+            GENESIS_IMPORT_LOCK_FILE,
+        },
         Config,
         DbType,
         RelayerConsensusConfig,
@@ -199,10 +203,41 @@ pub struct Command {
     #[clap(long = "time-until-synced", default_value = "0s", env)]
     pub time_until_synced: humantime::Duration,
 
+    /// Skips producing a block at an interval tick if there are no pending transactions.
+    #[clap(long = "poa-skip-empty-blocks", env)]
+    pub skip_empty_blocks: bool,
+
     /// The size of the memory pool in number of `MemoryInstance`s.
     #[clap(long = "memory-pool-size", default_value = "32", env)]
     pub memory_pool_size: usize,
 
+    /// Time allowed for importing the snapshot at genesis. Unlimited if not set.
+    #[clap(long = "genesis-import-timeout", env)]
+    pub genesis_import_timeout: Option<humantime::Duration>,
+
+    /// Checks after the snapshot import that no message is both unspent and spent.
+    #[clap(long = "genesis-validate-messages", env)]
+    pub genesis_validate_messages: bool,
+
+    /// Checks after the snapshot import that every historical block has its consensus
+    /// and every consensus has its block.
+    #[clap(long = "genesis-validate-consensus", env)]
+    pub genesis_validate_consensus: bool,
+
+    /// The maximum number of snapshot entries applied per write batch during the
+    /// snapshot import. Each group of the snapshot is applied at once if not set.
+    #[clap(long = "genesis-apply-batch-size", env)]
+    pub genesis_apply_batch_size: Option<usize>,
+
+    /// The maximum number of tables imported in parallel during the snapshot import.
+    /// Unlimited if not set.
+    #[clap(long = "genesis-max-concurrent-tables", env)]
+    pub genesis_max_concurrent_tables: Option<usize>,
+
+    /// The number of snapshot groups of a table read ahead of the one being imported.
+    #[clap(long = "genesis-prefetch-depth", default_value = "2", env)]
+    pub genesis_prefetch_depth: usize,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
@@ -237,7 +272,14 @@ impl Command {
             graphql,
             min_connected_reserved_peers,
             time_until_synced,
+            skip_empty_blocks,
             memory_pool_size,
+            genesis_import_timeout,
+            genesis_validate_messages,
+            genesis_validate_consensus,
+            genesis_apply_batch_size,
+            genesis_max_concurrent_tables,
+            genesis_prefetch_depth,
             profiling: _,
         } = self;
 
@@ -297,10 +339,13 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
+        let genesis_import_lock_file = (database_type == DbType::RocksDb)
+            .then(|| database_path.join(GENESIS_IMPORT_LOCK_FILE));
         let combined_db_config = CombinedDatabaseConfig {
             database_path,
             database_type,
             max_database_cache_size,
+            metrics,
         };
 
         let block_importer =
@@ -333,6 +378,7 @@ impl Command {
                 request_body_bytes_limit: graphql.graphql_request_body_bytes_limit,
                 api_request_timeout: graphql.api_request_timeout.into(),
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
+                max_page_size: graphql.graphql_max_page_size,
             },
             combined_db_config,
             snapshot_reader,
@@ -369,7 +415,18 @@ impl Command {
             relayer_consensus_config: verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
+            skip_empty_blocks,
             memory_pool_size,
+            genesis_import_timeout: genesis_import_timeout.map(Into::into),
+            genesis_validate_messages,
+            genesis_validate_consensus,
+            genesis_import: GenesisImportConfig {
+                max_concurrent_tables: genesis_max_concurrent_tables,
+                apply_batch_size: genesis_apply_batch_size,
+                prefetch_depth: genesis_prefetch_depth,
+                lock_file: genesis_import_lock_file,
+            },
+            test_block_gas_limit: None,
         };
         Ok(config)
     }
@@ -24,6 +24,10 @@ pub struct GraphQLArgs {
     #[clap(long = "graphql-max-recursive-depth", default_value = "16", env)]
     pub graphql_max_recursive_depth: usize,
 
+    /// The max number of entries returned in a page of the `blocks` query.
+    #[clap(long = "graphql-max-page-size", default_value = "10000", env)]
+    pub graphql_max_page_size: usize,
+
     /// The max body limit of the GraphQL query.
     #[clap(
         long = "graphql-request-body-bytes-limit",
@@ -16,6 +16,10 @@ pub enum TableEncoding {
     Parquet {
         tables: std::collections::HashMap<String, PathBuf>,
         latest_block_config_path: PathBuf,
+        /// Checksums of the groups of each table, in the order the groups were written.
+        #[serde(default)]
+        checksums:
+            std::collections::HashMap<String, Vec<fuel_core_types::fuel_types::Bytes32>>,
     },
 }
 impl TableEncoding {
@@ -187,6 +191,7 @@ mod tests {
                         "coins.parquet".into(),
                     )]),
                     latest_block_config_path: "latest_block_config.parquet".into(),
+                    checksums: Default::default(),
                 },
             };
             serde_json::to_writer(
@@ -211,6 +216,7 @@ mod tests {
                         latest_block_config_path: temp_dir
                             .path()
                             .join("latest_block_config.parquet"),
+                        checksums: Default::default(),
                     }
                 }
             );
@@ -229,6 +235,7 @@ mod tests {
                         dir.join("coins.parquet"),
                     )]),
                     latest_block_config_path: dir.join("latest_block_config.parquet"),
+                    checksums: Default::default(),
                 },
             };
 
@@ -250,6 +257,7 @@ mod tests {
                             "coins.parquet".into(),
                         )]),
                         latest_block_config_path: "latest_block_config.parquet".into(),
+                        checksums: Default::default(),
                     }
                 }
             );
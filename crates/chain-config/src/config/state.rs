@@ -624,6 +624,7 @@ impl StateConfig {
 pub use reader::{
     GroupIter,
     Groups,
+    SnapshotError,
     SnapshotReader,
 };
 #[cfg(feature = "parquet")]
@@ -675,6 +676,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parquet_corrupted_group_fails_checksum() {
+        use crate::TableEncoding;
+        use fuel_core_storage::kv_store::StorageColumn;
+
+        // given
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let coin = CoinConfig::randomize(&mut rng);
+        let owner = coin.owner;
+        let state = StateConfig {
+            coins: vec![coin],
+            ..Default::default()
+        };
+        let writer = SnapshotWriter::parquet(
+            temp_dir.path(),
+            writer::ZstdCompressionLevel::Uncompressed,
+        )
+        .unwrap();
+        let snapshot = writer
+            .write_state_config(state, &ChainConfig::local_testnet())
+            .unwrap();
+
+        let TableEncoding::Parquet { tables, .. } = &snapshot.table_encoding else {
+            panic!("Expected a parquet snapshot")
+        };
+        let coins_table = Coins::column().name().to_string();
+        let coins_path = &tables[&coins_table];
+        let mut bytes = std::fs::read(coins_path).unwrap();
+        let positions = bytes
+            .windows(owner.as_ref().len())
+            .positions(|window| window == owner.as_ref())
+            .collect_vec();
+        assert!(!positions.is_empty());
+        for position in positions {
+            bytes[position] ^= 0xff;
+        }
+        std::fs::write(coins_path, bytes).unwrap();
+
+        // when
+        let reader = SnapshotReader::open(snapshot).unwrap();
+        let result: anyhow::Result<Vec<_>> =
+            reader.read::<Coins>().unwrap().into_iter().collect();
+
+        // then
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SnapshotError>(),
+            Some(&SnapshotError::ChecksumMismatch {
+                table: coins_table,
+                group: 0
+            })
+        );
+    }
+
+    #[test]
+    fn snapshot_hash_follows_the_content_of_the_parquet_tables() {
+        use crate::TableEncoding;
+        use fuel_core_storage::kv_store::StorageColumn;
+
+        // given
+        let mut rng = StdRng::seed_from_u64(0);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let original_dir = temp_dir.path().join("original");
+        let moved_dir = temp_dir.path().join("moved");
+        let other_dir = temp_dir.path().join("other");
+        for dir in [&original_dir, &other_dir] {
+            let state = StateConfig {
+                coins: vec![CoinConfig::randomize(&mut rng)],
+                ..Default::default()
+            };
+            given_parquet_writer(dir)
+                .write_state_config(state, &ChainConfig::local_testnet())
+                .unwrap();
+        }
+        // Without the checksums, the hash has to read the tables.
+        let hash_of = |dir: &Path| {
+            let mut metadata = SnapshotMetadata::read(dir).unwrap();
+            if let TableEncoding::Parquet { checksums, .. } = &mut metadata.table_encoding
+            {
+                checksums.clear();
+            }
+            SnapshotReader::open(metadata)
+                .unwrap()
+                .snapshot_hash()
+                .unwrap()
+        };
+        let coins_path = |dir: &Path| {
+            let TableEncoding::Parquet { tables, .. } =
+                SnapshotMetadata::read(dir).unwrap().table_encoding
+            else {
+                panic!("Expected a parquet snapshot")
+            };
+            tables[Coins::column().name()].clone()
+        };
+        let original_hash = hash_of(&original_dir);
+
+        // when
+        std::fs::rename(&original_dir, &moved_dir).unwrap();
+        let moved_hash = hash_of(&moved_dir);
+        std::fs::copy(coins_path(&other_dir), coins_path(&moved_dir)).unwrap();
+        let replaced_hash = hash_of(&moved_dir);
+
+        // then
+        assert_eq!(moved_hash, original_hash);
+        assert_ne!(replaced_hash, original_hash);
+    }
+
     fn given_parquet_writer(path: &Path) -> SnapshotWriter {
         SnapshotWriter::parquet(path, writer::ZstdCompressionLevel::Level1).unwrap()
     }
@@ -822,6 +931,84 @@ mod tests {
         assert_eq!(coins.into_iter().count(), 0);
     }
 
+    #[test]
+    fn with_table_shards_merges_the_groups_of_all_shards_in_order() {
+        use crate::TableEncoding;
+        use fuel_core_storage::kv_store::StorageColumn;
+
+        // given
+        let mut rng = StdRng::seed_from_u64(0);
+        let state = StateConfig {
+            contracts: std::iter::repeat_with(|| ContractConfig::randomize(&mut rng))
+                .take(4)
+                .collect(),
+            ..Default::default()
+        };
+        let entries = AsTable::<ContractsState>::as_table(&state);
+        let (first_half, second_half) = entries.split_at(entries.len() / 2);
+        let write_shard = |dir: &Path, entries: &[TableEntry<ContractsState>]| {
+            let mut writer = given_parquet_writer(dir);
+            writer.write(entries.to_vec()).unwrap();
+            let snapshot = writer.close(None, &ChainConfig::local_testnet()).unwrap();
+            let TableEncoding::Parquet { tables, .. } = snapshot.table_encoding else {
+                panic!("Expected a parquet snapshot")
+            };
+            tables[ContractsState::column().name()].clone()
+        };
+        let first_dir = tempfile::tempdir().unwrap();
+        let second_dir = tempfile::tempdir().unwrap();
+        let shards = vec![
+            write_shard(first_dir.path(), first_half),
+            write_shard(second_dir.path(), second_half),
+        ];
+        let snapshot_dir = tempfile::tempdir().unwrap();
+        let snapshot = given_parquet_writer(snapshot_dir.path())
+            .close(None, &ChainConfig::local_testnet())
+            .unwrap();
+
+        // when
+        let reader = SnapshotReader::open(snapshot)
+            .unwrap()
+            .with_table_shards::<ContractsState>(shards)
+            .unwrap();
+
+        // then
+        let groups = reader.read_required::<ContractsState>().unwrap();
+        assert_eq!(groups.len(), 2);
+        let read: Vec<_> = groups.into_iter().map(|group| group.unwrap()).concat();
+        assert_eq!(read, entries);
+    }
+
+    #[test]
+    fn read_required_fails_for_table_missing_from_parquet_snapshot() {
+        use fuel_core_storage::kv_store::StorageColumn;
+
+        // given
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let state = StateConfig {
+            coins: vec![CoinConfig::randomize(&mut rng)],
+            ..Default::default()
+        };
+        let mut writer = given_parquet_writer(temp_dir.path());
+        writer.write(AsTable::<Coins>::as_table(&state)).unwrap();
+        let snapshot = writer.close(None, &ChainConfig::local_testnet()).unwrap();
+        let reader = SnapshotReader::open(snapshot).unwrap();
+
+        // when
+        let result = reader.read_required::<ContractsState>();
+
+        // then
+        let err = result.err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<SnapshotError>(),
+            Some(&SnapshotError::TableNotInSnapshot {
+                name: ContractsState::column().name().to_string()
+            })
+        );
+        assert_eq!(reader.read_required::<Coins>().unwrap().len(), 1);
+    }
+
     fn assert_roundtrip<T>(
         writer: impl FnOnce(&Path) -> SnapshotWriter,
         reader: impl FnOnce(SnapshotMetadata, usize) -> SnapshotReader,
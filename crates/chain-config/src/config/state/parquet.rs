@@ -1,6 +1,22 @@
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
+
 pub mod decode;
 pub mod encode;
 
+/// Digest of the encoded entries of a group. It is recorded in the snapshot metadata so
+/// that a corrupted group is detected before its entries are decoded.
+pub fn group_checksum(group: &[Vec<u8>]) -> Bytes32 {
+    let mut hasher = Hasher::default();
+    for entry in group {
+        hasher.input(u64::try_from(entry.len()).unwrap_or(u64::MAX).to_be_bytes());
+        hasher.input(entry);
+    }
+    hasher.finalize()
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
@@ -4,10 +4,15 @@ use fuel_core_storage::{
     structured_storage::TableWithBlueprint,
     Mappable,
 };
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
 use itertools::Itertools;
 
 use crate::{
     config::table_entry::TableEntry,
+    genesis::GenesisCommitment,
     AsTable,
     ChainConfig,
     LastBlockConfig,
@@ -27,7 +32,11 @@ where
         match &self.iter {
             GroupIter::InMemory { groups } => groups.len(),
             #[cfg(feature = "parquet")]
-            GroupIter::Parquet { decoder } => decoder.num_groups(),
+            GroupIter::Parquet { decoders, .. } => {
+                decoders.iter().fold(0usize, |groups, decoder| {
+                    groups.saturating_add(decoder.num_groups())
+                })
+            }
         }
     }
 
@@ -58,10 +67,79 @@ where
     },
     #[cfg(feature = "parquet")]
     Parquet {
-        decoder: super::parquet::decode::Decoder<std::fs::File>,
+        /// The files of the table, read one after another.
+        decoders:
+            std::collections::VecDeque<super::parquet::decode::Decoder<std::fs::File>>,
+        table: String,
+        checksums: Option<Vec<fuel_core_types::fuel_types::Bytes32>>,
+        group_index: usize,
+    },
+}
+
+/// Errors detected while reading or importing a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The group doesn't match the checksum recorded in the snapshot metadata.
+    ChecksumMismatch { table: String, group: usize },
+    /// The import didn't finish in time; `tables` are the ones still being imported.
+    ImportTimeout { tables: Vec<String> },
+    /// The `nonces` are both unspent in `Messages` and marked as spent in `SpentMessages`.
+    InconsistentMessageState {
+        nonces: Vec<fuel_core_types::fuel_types::Nonce>,
+    },
+    /// The snapshot has no entry for the table, e.g. because it predates the table.
+    TableNotInSnapshot { name: String },
+    /// Another import holds the `lock_file` of the database.
+    ImportAlreadyInProgress { lock_file: std::path::PathBuf },
+    /// The imported blocks and their consensus don't match: the blocks at
+    /// `blocks_without_consensus` have no consensus, and the consensus at
+    /// `consensus_without_block` has no block.
+    OrphanConsensus {
+        blocks_without_consensus: Vec<fuel_core_types::fuel_types::BlockHeight>,
+        consensus_without_block: Vec<fuel_core_types::fuel_types::BlockHeight>,
     },
 }
 
+impl core::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SnapshotError::ChecksumMismatch { table, group } => {
+                write!(f, "Checksum mismatch in group {group} of table '{table}'")
+            }
+            SnapshotError::ImportTimeout { tables } => {
+                write!(
+                    f,
+                    "Snapshot import timed out, unfinished tables: {tables:?}"
+                )
+            }
+            SnapshotError::InconsistentMessageState { nonces } => {
+                write!(f, "Messages are both unspent and spent: {nonces:?}")
+            }
+            SnapshotError::TableNotInSnapshot { name } => {
+                write!(f, "Table '{name}' is not present in the snapshot")
+            }
+            SnapshotError::ImportAlreadyInProgress { lock_file } => {
+                write!(
+                    f,
+                    "Another snapshot import is in progress, the lock file {} is held",
+                    lock_file.display()
+                )
+            }
+            SnapshotError::OrphanConsensus {
+                blocks_without_consensus,
+                consensus_without_block,
+            } => {
+                write!(
+                    f,
+                    "Blocks without consensus: {blocks_without_consensus:?}, consensus without block: {consensus_without_block:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
 #[cfg(feature = "parquet")]
 impl<T> Iterator for GroupIter<T>
 where
@@ -73,8 +151,33 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             GroupIter::InMemory { groups } => groups.next(),
-            GroupIter::Parquet { decoder } => {
-                let group = decoder.next()?.and_then(|byte_group| {
+            GroupIter::Parquet {
+                decoders,
+                table,
+                checksums,
+                group_index,
+            } => {
+                let byte_group = loop {
+                    match decoders.front_mut()?.next() {
+                        Some(byte_group) => break byte_group,
+                        None => {
+                            decoders.pop_front();
+                        }
+                    }
+                };
+                let index = *group_index;
+                *group_index = group_index.saturating_add(1);
+                let group = byte_group.and_then(|byte_group| {
+                    if let Some(checksums) = checksums {
+                        let checksum = super::parquet::group_checksum(&byte_group);
+                        if checksums.get(index) != Some(&checksum) {
+                            return Err(SnapshotError::ChecksumMismatch {
+                                table: table.clone(),
+                                group: index,
+                            }
+                            .into())
+                        }
+                    }
                     byte_group
                         .into_iter()
                         .map(|group| {
@@ -107,7 +210,12 @@ enum DataSource {
     #[cfg(feature = "parquet")]
     Parquet {
         tables: std::collections::HashMap<String, std::path::PathBuf>,
+        checksums:
+            std::collections::HashMap<String, Vec<fuel_core_types::fuel_types::Bytes32>>,
         latest_block_config: Option<LastBlockConfig>,
+        /// Tables split across multiple files, read in the given order instead of the
+        /// file in `tables`.
+        shards: std::collections::HashMap<String, Vec<std::path::PathBuf>>,
     },
     InMemory {
         state: StateConfig,
@@ -182,6 +290,10 @@ impl SnapshotReader {
     #[cfg(feature = "parquet")]
     fn parquet(
         tables: std::collections::HashMap<String, std::path::PathBuf>,
+        checksums: std::collections::HashMap<
+            String,
+            Vec<fuel_core_types::fuel_types::Bytes32>,
+        >,
         latest_block_config: std::path::PathBuf,
         chain_config: ChainConfig,
     ) -> anyhow::Result<Self> {
@@ -189,12 +301,40 @@ impl SnapshotReader {
         Ok(Self {
             data_source: DataSource::Parquet {
                 tables,
+                checksums,
                 latest_block_config,
+                shards: Default::default(),
             },
             chain_config,
         })
     }
 
+    /// Reads the table `T` from the `shards` instead of its file in the snapshot, e.g.
+    /// the files of the table in shard directories downloaded in parallel. The groups of
+    /// the shards are read in the order of `shards`, as if they were a single file. The checksums of the
+    /// snapshot don't cover the shards, so their groups aren't verified.
+    ///
+    /// Only parquet snapshots can be sharded.
+    #[cfg(feature = "parquet")]
+    pub fn with_table_shards<T>(
+        mut self,
+        shards: Vec<std::path::PathBuf>,
+    ) -> anyhow::Result<Self>
+    where
+        T: TableWithBlueprint,
+    {
+        use fuel_core_storage::kv_store::StorageColumn;
+        let DataSource::Parquet {
+            shards: table_shards,
+            ..
+        } = &mut self.data_source
+        else {
+            anyhow::bail!("Only parquet snapshots can be read from shards")
+        };
+        table_shards.insert(T::column().name().to_string(), shards);
+        Ok(self)
+    }
+
     #[cfg(feature = "parquet")]
     fn read_config<Config>(path: &std::path::Path) -> anyhow::Result<Config>
     where
@@ -236,8 +376,8 @@ impl SnapshotReader {
             TableEncoding::Parquet {
                 tables,
                 latest_block_config_path,
-                ..
-            } => Self::parquet(tables, latest_block_config_path, chain_config),
+                checksums,
+            } => Self::parquet(tables, checksums, latest_block_config_path, chain_config),
         }
     }
 
@@ -249,10 +389,31 @@ impl SnapshotReader {
     {
         let iter = match &self.data_source {
             #[cfg(feature = "parquet")]
-            DataSource::Parquet { tables, .. } => {
+            DataSource::Parquet {
+                tables,
+                checksums,
+                shards,
+                ..
+            } => {
                 use anyhow::Context;
                 use fuel_core_storage::kv_store::StorageColumn;
                 let name = T::column().name();
+                let open = |path: &std::path::PathBuf| {
+                    let file = std::fs::File::open(path).with_context(|| {
+                        format!("Could not open {path:?} in order to read table '{name}'")
+                    })?;
+                    super::parquet::decode::Decoder::new(file)
+                };
+                if let Some(shards) = shards.get(name) {
+                    return Ok(Groups {
+                        iter: GroupIter::Parquet {
+                            decoders: shards.iter().map(open).try_collect()?,
+                            table: name.to_string(),
+                            checksums: None,
+                            group_index: 0,
+                        },
+                    });
+                }
                 let Some(path) = tables.get(name) else {
                     return Ok(Groups {
                         iter: GroupIter::InMemory {
@@ -260,12 +421,11 @@ impl SnapshotReader {
                         },
                     });
                 };
-                let file = std::fs::File::open(path).with_context(|| {
-                    format!("Could not open {path:?} in order to read table '{name}'")
-                })?;
-
                 GroupIter::Parquet {
-                    decoder: super::parquet::decode::Decoder::new(file)?,
+                    decoders: [open(path)?].into(),
+                    table: name.to_string(),
+                    checksums: checksums.get(name).cloned(),
+                    group_index: 0,
                 }
             }
             DataSource::InMemory { state, group_size } => {
@@ -285,10 +445,118 @@ impl SnapshotReader {
         Ok(Groups { iter })
     }
 
+    /// Reads the groups of the table like [`Self::read`], but fails with
+    /// [`SnapshotError::TableNotInSnapshot`] if the snapshot has no entry for the table
+    /// instead of treating it as empty.
+    pub fn read_required<T>(&self) -> anyhow::Result<Groups<T>>
+    where
+        T: TableWithBlueprint,
+        StateConfig: AsTable<T>,
+        TableEntry<T>: serde::de::DeserializeOwned,
+    {
+        #[cfg(feature = "parquet")]
+        if let DataSource::Parquet { tables, shards, .. } = &self.data_source {
+            use fuel_core_storage::kv_store::StorageColumn;
+            let name = T::column().name();
+            if !tables.contains_key(name) && !shards.contains_key(name) {
+                return Err(SnapshotError::TableNotInSnapshot {
+                    name: name.to_string(),
+                }
+                .into());
+            }
+        }
+
+        self.read::<T>()
+    }
+
+    /// Returns `true` if none of the tables of the snapshot have entries.
+    pub fn is_empty(&self) -> bool {
+        match &self.data_source {
+            #[cfg(feature = "parquet")]
+            DataSource::Parquet { tables, shards, .. } => {
+                tables.is_empty() && shards.is_empty()
+            }
+            DataSource::InMemory { state, .. } => {
+                state.coins.is_empty()
+                    && state.messages.is_empty()
+                    && state.contracts.is_empty()
+            }
+        }
+    }
+
     pub fn chain_config(&self) -> &ChainConfig {
         &self.chain_config
     }
 
+    /// A hash identifying the snapshot, so that the progress of an interrupted import
+    /// isn't applied to a different snapshot.
+    ///
+    /// The tables of a parquet snapshot are identified by their content, so the hash
+    /// survives moving the snapshot but not replacing its files. The recorded checksums
+    /// stand in for the content of a table, otherwise its files are read in full.
+    pub fn snapshot_hash(&self) -> anyhow::Result<Bytes32> {
+        let data_source = match &self.data_source {
+            #[cfg(feature = "parquet")]
+            DataSource::Parquet {
+                tables,
+                checksums,
+                latest_block_config,
+                shards,
+            } => {
+                let names: std::collections::BTreeSet<_> =
+                    tables.keys().chain(shards.keys()).collect();
+                let tables = names
+                    .into_iter()
+                    .map(|name| {
+                        let content = match (shards.get(name), checksums.get(name)) {
+                            (Some(shards), _) => Self::hash_files(shards)?,
+                            (None, Some(checksums)) => {
+                                Hasher::default().extend_chain(checksums).finalize()
+                            }
+                            (None, None) => Self::hash_files(tables.get(name))?,
+                        };
+                        Ok((name, content))
+                    })
+                    .collect::<anyhow::Result<std::collections::BTreeMap<_, _>>>()?;
+                postcard::to_allocvec(&(tables, latest_block_config))
+            }
+            DataSource::InMemory { state, group_size } => {
+                postcard::to_allocvec(&(state, group_size))
+            }
+        }
+        .map_err(anyhow::Error::msg)?;
+
+        let hash = Hasher::default()
+            .chain(self.chain_config.root()?)
+            .chain(data_source)
+            .finalize();
+        Ok(hash)
+    }
+
+    /// Hashes the content of the `files`, in the given order.
+    #[cfg(feature = "parquet")]
+    fn hash_files<'a>(
+        files: impl IntoIterator<Item = &'a std::path::PathBuf>,
+    ) -> anyhow::Result<Bytes32> {
+        use std::io::Read;
+
+        let mut hasher = Hasher::default();
+        let mut buffer = vec![0u8; 64 * 1024];
+        for path in files {
+            let mut file = std::fs::File::open(path)?;
+            let mut file_hasher = Hasher::default();
+            loop {
+                let read = file.read(&mut buffer)?;
+                if read == 0 {
+                    break;
+                }
+                file_hasher.input(&buffer[..read]);
+            }
+            hasher.input(file_hasher.finalize());
+        }
+        Ok(hasher.finalize())
+    }
+
     pub fn last_block_config(&self) -> Option<&LastBlockConfig> {
         match &self.data_source {
             DataSource::InMemory { state, .. } => state.last_block.as_ref(),
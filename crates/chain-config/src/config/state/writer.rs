@@ -8,6 +8,8 @@ use crate::{
     TableEncoding,
 };
 use fuel_core_storage::structured_storage::TableWithBlueprint;
+#[cfg(feature = "parquet")]
+use fuel_core_types::fuel_types::Bytes32;
 use std::path::PathBuf;
 
 #[cfg(feature = "parquet")]
@@ -157,6 +159,7 @@ enum FragmentData {
     #[cfg(feature = "parquet")]
     Parquet {
         tables: std::collections::HashMap<String, PathBuf>,
+        checksums: std::collections::HashMap<String, Vec<Bytes32>>,
         compression: ZstdCompressionLevel,
     },
 }
@@ -176,14 +179,17 @@ impl FragmentData {
             (
                 FragmentData::Parquet {
                     tables,
+                    checksums,
                     compression,
                 },
                 FragmentData::Parquet {
                     tables: their_tables,
+                    checksums: their_checksums,
                     compression: their_compression,
                 },
             ) => {
                 tables.extend(their_tables);
+                checksums.extend(their_checksums);
                 anyhow::ensure!(*compression == their_compression, "Fragments use different compressions.")
             }
             #[cfg(feature="parquet")]
@@ -226,6 +232,7 @@ impl SnapshotFragment {
             #[cfg(feature = "parquet")]
             FragmentData::Parquet {
                 tables,
+                checksums,
                 compression,
             } => {
                 let latest_block_config_path =
@@ -239,6 +246,7 @@ impl SnapshotFragment {
                 TableEncoding::Parquet {
                     tables,
                     latest_block_config_path,
+                    checksums,
                 }
             }
         };
@@ -370,9 +378,10 @@ impl SnapshotWriter {
                 compression,
                 ..
             } => {
-                let tables = table_encoders.close()?;
+                let (tables, checksums) = table_encoders.close()?;
                 FragmentData::Parquet {
                     tables,
+                    checksums,
                     compression,
                 }
             }
@@ -389,12 +398,17 @@ impl SnapshotWriter {
 struct PostcardParquetEncoder {
     path: PathBuf,
     encoder: parquet::encode::Encoder<std::fs::File>,
+    checksums: Vec<Bytes32>,
 }
 
 #[cfg(feature = "parquet")]
 impl PostcardParquetEncoder {
     pub fn new(path: PathBuf, encoder: parquet::encode::Encoder<std::fs::File>) -> Self {
-        Self { path, encoder }
+        Self {
+            path,
+            encoder,
+            checksums: vec![],
+        }
     }
 
     fn write<T>(&mut self, elements: Vec<TableEntry<T>>) -> anyhow::Result<()>
@@ -407,6 +421,7 @@ impl PostcardParquetEncoder {
             .into_iter()
             .map(|entry| postcard::to_stdvec(&entry))
             .try_collect()?;
+        self.checksums.push(parquet::group_checksum(&encoded));
         self.encoder.write(encoded)
     }
 }
@@ -452,13 +467,21 @@ impl TableEncoders {
         Ok(encoder)
     }
 
-    fn close(self) -> anyhow::Result<std::collections::HashMap<String, PathBuf>> {
+    #[allow(clippy::type_complexity)]
+    fn close(
+        self,
+    ) -> anyhow::Result<(
+        std::collections::HashMap<String, PathBuf>,
+        std::collections::HashMap<String, Vec<Bytes32>>,
+    )> {
         let mut files = std::collections::HashMap::new();
+        let mut checksums = std::collections::HashMap::new();
         for (file, encoder) in self.encoders {
             encoder.encoder.close()?;
-            files.insert(file, encoder.path);
+            files.insert(file.clone(), encoder.path);
+            checksums.insert(file, encoder.checksums);
         }
-        Ok(files)
+        Ok((files, checksums))
     }
 }
 
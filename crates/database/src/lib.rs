@@ -59,6 +59,15 @@ pub enum Error {
         prev_height: u64,
     },
 
+    /// The contract code exceeds the configured maximum size.
+    #[display(fmt = "The contract code size {size} exceeds the maximum size {limit}")]
+    ContractCodeTooLarge {
+        /// The size of the contract code.
+        size: usize,
+        /// The maximum allowed size of the contract code.
+        limit: usize,
+    },
+
     /// Not related to database error.
     #[from]
     Other(anyhow::Error),
@@ -21,11 +21,22 @@ use fuel_core_storage::tables::{
     Coins,
     ContractsAssets,
     ContractsLatestUtxo,
-    ContractsRawCode,
     ContractsState,
     Messages,
 };
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    tables::ContractsRawCode,
+    transactional::AtomicView,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Salt,
+    },
+    fuel_vm::Contract,
+};
 use std::path::PathBuf;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -33,6 +44,8 @@ pub struct CombinedDatabaseConfig {
     pub database_path: PathBuf,
     pub database_type: DbType,
     pub max_database_cache_size: usize,
+    /// Counts the operations on the contract columns in the database metrics.
+    pub metrics: bool,
 }
 
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
@@ -107,7 +120,20 @@ impl CombinedDatabase {
             _ => CombinedDatabase::in_memory(),
         };
 
-        Ok(combined_database)
+        if config.metrics {
+            Ok(combined_database.with_contract_metrics())
+        } else {
+            Ok(combined_database)
+        }
+    }
+
+    /// Counts the operations on the contract columns of the on-chain database
+    /// in the database metrics.
+    pub fn with_contract_metrics(self) -> Self {
+        Self {
+            on_chain: self.on_chain.with_contract_metrics(),
+            ..self
+        }
     }
 
     pub fn in_memory() -> Self {
@@ -152,11 +178,29 @@ impl CombinedDatabase {
         &mut self.relayer
     }
 
+    /// Returns the code and the salt of the contract, or `None` if either is missing.
+    /// The code is stored on-chain and the salt off-chain, so both are read from views
+    /// taken together, before any of them is accessed.
+    pub fn contract_code_and_salt(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<(Contract, Salt)>> {
+        let on_chain = self.on_chain.latest_view()?;
+        let off_chain = self.off_chain.latest_view()?;
+        let Some(salt) = off_chain.contract_salt(contract_id)? else {
+            return Ok(None);
+        };
+        let code = on_chain
+            .storage::<ContractsRawCode>()
+            .get(contract_id)?
+            .map(|code| (code.into_owned(), salt));
+        Ok(code)
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn read_state_config(&self) -> StorageResult<StateConfig> {
         use fuel_core_chain_config::AddTable;
         use fuel_core_producer::ports::BlockProducerDatabase;
-        use fuel_core_storage::transactional::AtomicView;
         use itertools::Itertools;
         let mut builder = StateConfigBuilder::default();
 
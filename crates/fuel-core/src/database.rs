@@ -13,6 +13,7 @@ use crate::{
     },
     graphql_api::storage::blocks::FuelBlockIdsToHeights,
     state::{
+        contract_metrics::ContractMetrics,
         data_source::{
             DataSource,
             DataSourceType,
@@ -30,11 +31,13 @@ use fuel_core_chain_config::TableEntry;
 use fuel_core_services::SharedMutex;
 use fuel_core_storage::{
     self,
+    column::Column,
     iter::{
         IterDirection,
         IterableTable,
         IteratorOverTable,
     },
+    kv_store::WriteOperation,
     not_found,
     tables::FuelBlocks,
     transactional::{
@@ -96,6 +99,8 @@ where
 {
     /// Cached value from Metadata table, used to speed up lookups.
     height: SharedMutex<Option<Description::Height>>,
+    /// The maximum size of the contract code accepted on commit, if any.
+    max_contract_code_size: Option<usize>,
 }
 
 impl<Description> Default for RegularStage<Description>
@@ -105,6 +110,7 @@ where
     fn default() -> Self {
         Self {
             height: SharedMutex::new(None),
+            max_contract_code_size: None,
         }
     }
 }
@@ -172,6 +178,7 @@ where
             data_source,
             RegularStage {
                 height: SharedMutex::new(None),
+                max_contract_code_size: None,
             },
         ));
         let height = database
@@ -278,8 +285,46 @@ where
     }
 }
 
+impl Database<OnChain> {
+    /// Rejects the commits that insert a contract code larger than `limit` bytes.
+    /// `None` disables the check.
+    pub fn with_max_contract_code_size(mut self, limit: Option<usize>) -> Self {
+        self.stage.max_contract_code_size = limit;
+        self
+    }
+
+    /// Counts the operations on the contract columns in the database metrics.
+    pub fn with_contract_metrics(self) -> Self {
+        let DataSource { data, stage } = self.into_inner();
+        let data = Arc::new(ContractMetrics::new(data));
+        Self::from_storage(DataSource::new(data, stage))
+    }
+
+    fn ensure_contract_code_size(&self, changes: &Changes) -> StorageResult<()> {
+        let Some(limit) = self.stage.max_contract_code_size else {
+            return Ok(())
+        };
+        let Some(codes) = changes.get(&Column::ContractsRawCode.as_u32()) else {
+            return Ok(())
+        };
+        for operation in codes.values() {
+            if let WriteOperation::Insert(code) = operation {
+                if code.len() > limit {
+                    return Err(DatabaseError::ContractCodeTooLarge {
+                        size: code.len(),
+                        limit,
+                    }
+                    .into())
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Modifiable for Database<OnChain> {
     fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+        self.ensure_contract_code_size(&changes)?;
         commit_changes_with_height_update(self, changes, |iter| {
             iter.iter_all::<FuelBlocks>(Some(IterDirection::Reverse))
                 .map(|result| result.map(|(height, _)| height))
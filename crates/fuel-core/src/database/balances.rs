@@ -6,9 +6,11 @@ use fuel_core_storage::{
     },
     ContractsAssetKey,
     Error as StorageError,
+    StorageAsMut,
     StorageAsRef,
     StorageBatchMutate,
     StorageInspect,
+    StorageMutate,
 };
 use fuel_core_types::{
     fuel_asm::Word,
@@ -18,6 +20,7 @@ use fuel_core_types::{
     },
 };
 use itertools::Itertools;
+use std::borrow::Cow;
 
 pub trait BalancesInitializer {
     /// Initialize the balances of the contract from the all leaves.
@@ -100,6 +103,93 @@ where
     }
 }
 
+/// A change of a contract balance recorded by the [`BalanceChangeRecorder`]. A missing
+/// balance is `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceChange {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub old: Option<Word>,
+    pub new: Option<Word>,
+}
+
+/// Wraps a storage, e.g. a database transaction, and records the changes of every
+/// `ContractsAssets` insert and remove done through it. The changes are returned with
+/// the storage by [`BalanceChangeRecorder::into_parts`], so they can be read after the
+/// transaction is committed.
+pub struct BalanceChangeRecorder<S> {
+    inner: S,
+    changes: Vec<BalanceChange>,
+}
+
+impl<S> BalanceChangeRecorder<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            changes: Vec::new(),
+        }
+    }
+
+    /// The changes recorded so far, in the order they were made.
+    pub fn changes(&self) -> &[BalanceChange] {
+        &self.changes
+    }
+
+    /// Returns the wrapped storage and the recorded changes.
+    pub fn into_parts(self) -> (S, Vec<BalanceChange>) {
+        (self.inner, self.changes)
+    }
+
+    fn record(&mut self, key: &ContractsAssetKey, old: Option<Word>, new: Option<Word>) {
+        self.changes.push(BalanceChange {
+            contract_id: *key.contract_id(),
+            asset_id: *key.asset_id(),
+            old,
+            new,
+        });
+    }
+}
+
+impl<S> StorageInspect<ContractsAssets> for BalanceChangeRecorder<S>
+where
+    S: StorageInspect<ContractsAssets, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(&self, key: &ContractsAssetKey) -> Result<Option<Cow<Word>>, Self::Error> {
+        self.inner.storage::<ContractsAssets>().get(key)
+    }
+
+    fn contains_key(&self, key: &ContractsAssetKey) -> Result<bool, Self::Error> {
+        self.inner.storage::<ContractsAssets>().contains_key(key)
+    }
+}
+
+impl<S> StorageMutate<ContractsAssets> for BalanceChangeRecorder<S>
+where
+    S: StorageMutate<ContractsAssets, Error = StorageError>,
+{
+    fn insert(
+        &mut self,
+        key: &ContractsAssetKey,
+        value: &Word,
+    ) -> Result<Option<Word>, Self::Error> {
+        let old = self
+            .inner
+            .storage_as_mut::<ContractsAssets>()
+            .insert(key, value)?;
+        self.record(key, old, Some(*value));
+        Ok(old)
+    }
+
+    fn remove(&mut self, key: &ContractsAssetKey) -> Result<Option<Word>, Self::Error> {
+        let old = self.inner.storage_as_mut::<ContractsAssets>().remove(key)?;
+        self.record(key, old, None);
+        Ok(old)
+    }
+}
+
+#[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
     use std::{
@@ -112,9 +202,9 @@ mod tests {
         database_description::on_chain::OnChain,
         Database,
     };
-    use fuel_core_storage::{
-        transactional::IntoTransaction,
-        StorageAsMut,
+    use fuel_core_storage::transactional::{
+        IntoTransaction,
+        WriteTransaction,
     };
     use fuel_core_types::fuel_types::AssetId;
     use rand::{
@@ -187,6 +277,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn balance_change_recorder__records_every_balance_change() {
+        // given
+        let contract_id = ContractId::from([1; 32]);
+        let first_asset = AssetId::from([2; 32]);
+        let second_asset = AssetId::from([3; 32]);
+        let first_key = ContractsAssetKey::new(&contract_id, &first_asset);
+        let second_key = ContractsAssetKey::new(&contract_id, &second_asset);
+        let mut database = Database::<OnChain>::default();
+        let mut recorder = BalanceChangeRecorder::new(database.write_transaction());
+
+        // when
+        recorder
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&first_key, &10)
+            .unwrap();
+        recorder
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&first_key, &15)
+            .unwrap();
+        recorder
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&second_key, &7)
+            .unwrap();
+        recorder
+            .storage_as_mut::<ContractsAssets>()
+            .remove(&first_key)
+            .unwrap();
+        let (transaction, changes) = recorder.into_parts();
+        transaction.commit().unwrap();
+
+        // then
+        let change = |asset_id, old, new| BalanceChange {
+            contract_id,
+            asset_id,
+            old,
+            new,
+        };
+        assert_eq!(
+            changes,
+            vec![
+                change(first_asset, None, Some(10)),
+                change(first_asset, Some(10), Some(15)),
+                change(second_asset, None, Some(7)),
+                change(first_asset, Some(15), None),
+            ]
+        );
+        let balance = |key| {
+            database
+                .storage::<ContractsAssets>()
+                .get(key)
+                .unwrap()
+                .map(Cow::into_owned)
+        };
+        assert_eq!(balance(&first_key), None);
+        assert_eq!(balance(&second_key), Some(7));
+    }
+
     fn random_bytes<R>(rng: &mut R) -> [u8; 32]
     where
         R: Rng + ?Sized,
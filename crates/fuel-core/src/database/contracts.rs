@@ -1,26 +1,120 @@
-use crate::database::OnChainIterableKeyValueView;
-use fuel_core_chain_config::TableEntry;
+use crate::{
+    database::{
+        balances::BalancesInitializer,
+        state::StateInitializer,
+        Database,
+        OffChainIterableKeyValueView,
+        OnChainIterableKeyValueView,
+    },
+    fuel_core_graphql_api::storage::contracts::ContractsInfo,
+};
+use fuel_core_chain_config::{
+    ContractBalanceConfig,
+    ContractConfig,
+    ContractStateConfig,
+    TableEntry,
+};
 use fuel_core_storage::{
+    column::Column,
     iter::{
         IterDirection,
+        IterableStore,
         IteratorOverTable,
     },
+    kv_store::KeyValueInspect,
     not_found,
     tables::{
+        merkle::ContractsStateMerkleMetadata,
         ContractsAssets,
         ContractsLatestUtxo,
         ContractsRawCode,
         ContractsState,
     },
+    transactional::WriteTransaction,
     ContractsAssetKey,
+    ContractsStateKey,
+    Error as StorageError,
     Result as StorageResult,
+    StorageAsMut,
     StorageAsRef,
+    StorageSize,
 };
-use fuel_core_types::fuel_types::{
-    AssetId,
-    ContractId,
+use fuel_core_types::{
+    entities::contract::ContractUtxoInfo,
+    fuel_tx::{
+        Contract,
+        Salt,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+        Word,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
 };
 use itertools::Itertools;
+use std::{
+    cmp::Reverse,
+    collections::{
+        BinaryHeap,
+        HashMap,
+    },
+    ops::Bound,
+};
+
+/// Errors returned when the contract tables are inconsistent with each other.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ContractDbError {
+    #[error("The info (latest UTXO) of the contract {contract_id} is missing")]
+    MissingInfo { contract_id: ContractId },
+    #[error("Expected an entry of the contract {expected}, but found one of {found}")]
+    MismatchedId {
+        expected: ContractId,
+        found: ContractId,
+    },
+    #[error("The state of the contract {contract_id} has a malformed key")]
+    MalformedStateKey { contract_id: ContractId },
+    #[error("The config of the contract {contract_id} sets the state slot {key} twice")]
+    DuplicateStateKey {
+        contract_id: ContractId,
+        key: Bytes32,
+    },
+    #[error(
+        "The config of the contract {contract_id} sets the balance of {asset_id} twice"
+    )]
+    DuplicateBalance {
+        contract_id: ContractId,
+        asset_id: AssetId,
+    },
+}
+
+/// A lightweight summary of a deployed contract, without its code, state and balances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ContractSummary {
+    /// The id of the contract
+    pub contract_id: ContractId,
+    /// The size of the contract bytecode in bytes
+    pub code_size: usize,
+    /// The number of state slots of the contract
+    pub state_count: u64,
+    /// The number of assets the contract holds a balance of
+    pub balance_count: u64,
+    /// The block height of the tx pointer of the contract's latest UTXO
+    pub tx_pointer_block_height: BlockHeight,
+    /// The transaction index of the tx pointer of the contract's latest UTXO
+    pub tx_pointer_tx_idx: u16,
+}
+
+impl From<ContractDbError> for StorageError {
+    fn from(e: ContractDbError) -> Self {
+        StorageError::Other(e.into())
+    }
+}
 
 impl OnChainIterableKeyValueView {
     pub fn iter_contract_state(
@@ -64,49 +158,661 @@ impl OnChainIterableKeyValueView {
             .ok_or_else(|| not_found!("ContractsRawCode"))
     }
 
+    /// Returns the length of the contract bytecode, or `None` if the contract is unknown.
+    /// Only the size of the code is looked up, the bytecode itself is not read.
+    pub fn contract_code_len(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<usize>> {
+        StorageSize::<ContractsRawCode>::size_of_value(self, contract_id)
+    }
+
+    /// Returns whether each of the `contract_ids` has its code deployed, in the order of
+    /// the `contract_ids`.
+    pub fn contracts_exist(
+        &self,
+        contract_ids: &[ContractId],
+    ) -> StorageResult<Vec<bool>> {
+        contract_ids
+            .iter()
+            .map(|contract_id| {
+                self.storage::<ContractsRawCode>().contains_key(contract_id)
+            })
+            .try_collect()
+    }
+
+    /// Returns the configs of the contracts whose ids satisfy the `predicate`, ordered by
+    /// `ContractId` in the given `direction`: ascending for [`IterDirection::Forward`]
+    /// and descending for [`IterDirection::Reverse`].
+    ///
+    /// The ids are taken from the keys of the latest UTXO table, and the predicate is
+    /// applied before the UTXO, code, state and balances of the contract are read.
+    pub fn get_contract_configs_where<F>(
+        &self,
+        direction: IterDirection,
+        predicate: F,
+    ) -> StorageResult<Vec<ContractConfig>>
+    where
+        F: Fn(&ContractId) -> bool,
+    {
+        self.latest_utxo_contract_ids(direction)
+            .filter_ok(|contract_id| predicate(contract_id))
+            .map(|contract_id| self.get_contract_config_by_id(contract_id?))
+            .try_collect()
+    }
+
+    /// Yields the configs of all contracts one at a time, ordered by `ContractId`. Each
+    /// config is only read when the iterator advances, so at most one config is held
+    /// in memory, unlike [`Self::get_contract_configs_where`].
+    pub fn contract_configs_iter(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractConfig>> + '_ {
+        self.latest_utxo_contract_ids(IterDirection::Forward)
+            .map(|contract_id| self.get_contract_config_by_id(contract_id?))
+    }
+
+    /// Yields the ids of the contracts in the latest UTXO table, ordered by `ContractId`
+    /// in the given `direction`. Only the keys are visited, the UTXOs are not read.
+    fn latest_utxo_contract_ids(
+        &self,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<ContractId>> + '_ {
+        self.iter_keys_only(Column::ContractsLatestUtxo, None, direction)
+            .map(contract_id_from_key)
+    }
+
+    /// Returns the config of the contract. The code, latest UTXO, state and balances
+    /// are all read from this view, so they belong to the same snapshot even if the
+    /// database is modified concurrently.
+    pub fn get_contract_config_by_id(
+        &self,
+        contract_id: ContractId,
+    ) -> StorageResult<ContractConfig> {
+        let utxo = self
+            .contract_latest_utxo(&contract_id)?
+            .ok_or(ContractDbError::MissingInfo { contract_id })?;
+        self.contract_config(contract_id, utxo)
+    }
+
+    /// Returns the configs of the `contract_ids`, ordered by the first occurrence of each
+    /// id. A repeated id is only read once and yields a single config, so the result may
+    /// be shorter than `contract_ids`.
+    pub fn get_contract_configs(
+        &self,
+        contract_ids: &[ContractId],
+    ) -> StorageResult<Vec<ContractConfig>> {
+        contract_ids
+            .iter()
+            .unique()
+            .map(|contract_id| self.get_contract_config_by_id(*contract_id))
+            .try_collect()
+    }
+
+    fn contract_config(
+        &self,
+        contract_id: ContractId,
+        utxo: ContractUtxoInfo,
+    ) -> StorageResult<ContractConfig> {
+        let code = self.contract_code(contract_id)?.value.into();
+        let states = self
+            .iter_all_by_prefix::<ContractsState, _>(Some(contract_id))
+            .map(|result| {
+                let (key, value) = result.map_err(|e| match e {
+                    StorageError::Codec(_) => {
+                        ContractDbError::MalformedStateKey { contract_id }.into()
+                    }
+                    e => e,
+                })?;
+                ensure_same_contract(contract_id, *key.contract_id())?;
+                Ok(ContractStateConfig {
+                    key: *key.state_key(),
+                    value: value.into(),
+                })
+            })
+            .try_collect::<_, _, StorageError>()?;
+        let balances = self
+            .iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id))
+            .map(|result| {
+                let (key, amount) = result?;
+                ensure_same_contract(contract_id, *key.contract_id())?;
+                Ok(ContractBalanceConfig {
+                    asset_id: *key.asset_id(),
+                    amount,
+                })
+            })
+            .try_collect::<_, _, StorageError>()?;
+
+        Ok(ContractConfig {
+            contract_id,
+            code,
+            tx_id: *utxo.utxo_id().tx_id(),
+            output_index: utxo.utxo_id().output_index(),
+            tx_pointer_block_height: utxo.tx_pointer().block_height(),
+            tx_pointer_tx_idx: utxo.tx_pointer().tx_index(),
+            states,
+            balances,
+        })
+    }
+
+    /// Returns the latest UTXO of the contract, or `None` if the contract is unknown.
     pub fn contract_latest_utxo(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<ContractUtxoInfo>> {
+        let utxo = self
+            .storage::<ContractsLatestUtxo>()
+            .get(contract_id)?
+            .map(|utxo| utxo.into_owned());
+        Ok(utxo)
+    }
+
+    /// Returns the block height and transaction index from the tx pointer of the contract's
+    /// latest UTXO, or `None` if the contract is unknown. The UTXO is replaced whenever a
+    /// transaction uses the contract, so this is the deployment only for contracts that
+    /// haven't been used since.
+    pub fn contract_origin(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<(BlockHeight, u16)>> {
+        let origin = self.contract_latest_utxo(contract_id)?.map(|utxo| {
+            let tx_pointer = utxo.tx_pointer();
+            (tx_pointer.block_height(), tx_pointer.tx_index())
+        });
+        Ok(origin)
+    }
+
+    /// Returns the ids of the contracts whose latest UTXO was created in a block within
+    /// `from..=to`, ordered by `ContractId`. As for [`Self::contract_origin`], the height
+    /// is the deployment height only for contracts that haven't been used since.
+    ///
+    /// All contracts are scanned, there is no index by height.
+    pub fn contracts_deployed_in_range(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> StorageResult<Vec<ContractId>> {
+        self.iter_all::<ContractsLatestUtxo>(None)
+            .filter_map_ok(|(contract_id, utxo)| {
+                let height = utxo.tx_pointer().block_height();
+                (from <= height && height <= to).then_some(contract_id)
+            })
+            .try_collect()
+    }
+
+    /// Returns the [`ContractSummary`] of the contract. The size of the code is read
+    /// without loading the code, and only the keys of the state and balances are visited.
+    pub fn contract_summary(
         &self,
         contract_id: ContractId,
-    ) -> StorageResult<TableEntry<ContractsLatestUtxo>> {
-        self.storage::<ContractsLatestUtxo>()
-            .get(&contract_id)?
-            .map(|value| TableEntry {
-                key: contract_id,
-                value: value.into_owned(),
+    ) -> StorageResult<ContractSummary> {
+        let code_size =
+            StorageSize::<ContractsRawCode>::size_of_value(self, &contract_id)?
+                .ok_or_else(|| not_found!("ContractsRawCode"))?;
+        let utxo = self
+            .contract_latest_utxo(&contract_id)?
+            .ok_or(ContractDbError::MissingInfo { contract_id })?;
+
+        Ok(ContractSummary {
+            contract_id,
+            code_size,
+            state_count: self
+                .count_contract_keys(Column::ContractsState, &contract_id)?,
+            balance_count: self
+                .count_contract_keys(Column::ContractsAssets, &contract_id)?,
+            tx_pointer_block_height: utxo.tx_pointer().block_height(),
+            tx_pointer_tx_idx: utxo.tx_pointer().tx_index(),
+        })
+    }
+
+    fn count_contract_keys(
+        &self,
+        column: Column,
+        contract_id: &ContractId,
+    ) -> StorageResult<u64> {
+        self.iter_store_keys(
+            column,
+            Some(contract_id.as_slice()),
+            None,
+            IterDirection::Forward,
+        )
+        .try_fold(0u64, |count, key| {
+            let (key_contract_id, _) = split_contract_prefixed_key(&key?)?;
+            ensure_same_contract(*contract_id, key_contract_id)?;
+            Ok(count.saturating_add(1))
+        })
+    }
+
+    /// Streams the state of the contract into the `writer` and returns the number of
+    /// slots written. Every slot is encoded as its 32-byte key, followed by the length
+    /// of its value as a big-endian `u32` and the value itself. The slots are written
+    /// one by one while iterating, so the state is never held in memory as a whole.
+    pub fn export_contract_state<W: std::io::Write>(
+        &self,
+        contract_id: ContractId,
+        writer: &mut W,
+    ) -> StorageResult<u64> {
+        self.iter_all_by_prefix::<ContractsState, _>(Some(contract_id))
+            .try_fold(0u64, |count, result| -> StorageResult<u64> {
+                let (key, value) = result?;
+                ensure_same_contract(contract_id, *key.contract_id())?;
+                let value = value.0.as_slice();
+                let len = u32::try_from(value.len()).map_err(|_| {
+                    anyhow::anyhow!("The state value is too large to export")
+                })?;
+                writer
+                    .write_all(key.state_key().as_ref())
+                    .and_then(|_| writer.write_all(&len.to_be_bytes()))
+                    .and_then(|_| writer.write_all(value))
+                    .map_err(|e| StorageError::Other(e.into()))?;
+                Ok(count.saturating_add(1))
             })
-            .ok_or_else(|| not_found!("ContractsLatestUtxo"))
     }
 
+    /// Returns the Merkle root of the contract state, or `None` if the contract has no
+    /// state. The root is read from the Merkle metadata kept alongside the state, so the
+    /// state itself isn't visited.
+    pub fn contract_state_root(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<Option<Bytes32>> {
+        let root = self
+            .storage::<ContractsStateMerkleMetadata>()
+            .get(contract_id)?
+            .map(|metadata| Bytes32::from(*metadata.root()));
+        Ok(root)
+    }
+
+    /// Iterates over the balances of the `contract` in the given `direction`, starting
+    /// from the `start_asset` bound. An excluded bound skips the balance of that asset,
+    /// which lets callers resume pagination after the last asset they have seen.
     pub fn filter_contract_balances(
         &self,
         contract: ContractId,
-        start_asset: Option<AssetId>,
+        start_asset: Bound<AssetId>,
         direction: Option<IterDirection>,
     ) -> impl Iterator<Item = StorageResult<TableEntry<ContractsAssets>>> + '_ {
-        let start_asset =
-            start_asset.map(|asset| ContractsAssetKey::new(&contract, &asset));
+        let (start_key, excluded_asset) = match start_asset {
+            Bound::Included(asset) => {
+                (Some(ContractsAssetKey::new(&contract, &asset)), None)
+            }
+            Bound::Excluded(asset) => {
+                (Some(ContractsAssetKey::new(&contract, &asset)), Some(asset))
+            }
+            Bound::Unbounded => (None, None),
+        };
         self.iter_all_filtered::<ContractsAssets, _>(
             Some(contract),
-            start_asset.as_ref(),
+            start_key.as_ref(),
             direction,
         )
+        .filter_ok(move |(key, _)| Some(key.asset_id()) != excluded_asset.as_ref())
         .map_ok(|(key, value)| TableEntry { key, value })
     }
+
+    /// Returns the balance of the `asset` held by the `contract`, or zero if it holds none.
+    pub fn contract_asset_balance(
+        &self,
+        contract: ContractId,
+        asset: AssetId,
+    ) -> StorageResult<Word> {
+        let balance = self
+            .storage::<ContractsAssets>()
+            .get(&ContractsAssetKey::new(&contract, &asset))?
+            .map(|balance| balance.into_owned())
+            .unwrap_or_default();
+        Ok(balance)
+    }
+
+    /// Returns whether the `key` slot of the `contract_id` state is set, without
+    /// reading its value.
+    pub fn contract_state_contains(
+        &self,
+        contract_id: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<bool> {
+        self.storage::<ContractsState>()
+            .contains_key(&ContractsStateKey::new(contract_id, key))
+    }
+
+    /// Returns the number of deployed contracts. Only the keys of the contracts' code
+    /// are visited, the code itself is not read.
+    pub fn contract_count(&self) -> StorageResult<u64> {
+        self.iter_keys_only(Column::ContractsRawCode, None, IterDirection::Forward)
+            .try_fold(0u64, |count, item| item.map(|_| count.saturating_add(1)))
+    }
+
+    /// Returns the number of contracts per code size bucket. The `buckets` are the
+    /// ascending upper bounds (inclusive) of the buckets: the entry `i` of the result
+    /// counts the contracts with a code size in `(buckets[i - 1], buckets[i]]`, and the
+    /// additional last entry the contracts larger than the last bound.
+    ///
+    /// Only the sizes of the code are looked up, the bytecode itself is not read.
+    pub fn contract_code_size_histogram(
+        &self,
+        buckets: &[usize],
+    ) -> StorageResult<Vec<u64>> {
+        let mut histogram = vec![0u64; buckets.len().saturating_add(1)];
+        for key in
+            self.iter_keys_only(Column::ContractsRawCode, None, IterDirection::Forward)
+        {
+            let size =
+                KeyValueInspect::size_of_value(self, &key?, Column::ContractsRawCode)?
+                    .unwrap_or_default();
+            let bucket = buckets.partition_point(|bound| *bound < size);
+            if let Some(count) = histogram.get_mut(bucket) {
+                *count = count.saturating_add(1);
+            }
+        }
+        Ok(histogram)
+    }
+
+    /// Returns the total balance of each asset held across all contracts.
+    pub fn all_contract_assets(&self) -> StorageResult<HashMap<AssetId, Word>> {
+        self.iter_all::<ContractsAssets>(None).try_fold(
+            HashMap::new(),
+            |mut totals, item| {
+                let (key, balance) = item?;
+                let total = totals.entry(*key.asset_id()).or_insert(0u64);
+                *total = total.saturating_add(balance);
+                Ok(totals)
+            },
+        )
+    }
+
+    /// Returns up to `n` contracts holding the most of the `asset`, ordered by the balance
+    /// descending and then by `ContractId` ascending.
+    ///
+    /// The balances are keyed by the contract first, so this is a full scan of all the
+    /// contract balances of all assets. Only the `n` largest holders are kept in memory.
+    pub fn top_contract_holders(
+        &self,
+        asset: AssetId,
+        n: usize,
+    ) -> StorageResult<Vec<(ContractId, Word)>> {
+        let mut top = BinaryHeap::with_capacity(n.saturating_add(1));
+        for item in self.iter_all::<ContractsAssets>(None) {
+            let (key, balance) = item?;
+            if *key.asset_id() != asset {
+                continue
+            }
+            top.push(Reverse((balance, Reverse(*key.contract_id()))));
+            if top.len() > n {
+                top.pop();
+            }
+        }
+        let holders = top
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((balance, Reverse(contract_id)))| (contract_id, balance))
+            .collect();
+        Ok(holders)
+    }
+
+    /// Returns up to `limit` contract ids following the `cursor` (exclusive) in the
+    /// given `direction`, along with whether more contracts remain after the page.
+    pub fn contracts_page(
+        &self,
+        cursor: Option<ContractId>,
+        limit: usize,
+        direction: IterDirection,
+    ) -> StorageResult<(Vec<ContractId>, bool)> {
+        let start = cursor.as_ref().map(|cursor| cursor.as_slice());
+        let mut contracts: Vec<ContractId> = self
+            .iter_keys_only(Column::ContractsRawCode, start, direction)
+            .map(contract_id_from_key)
+            .filter_ok(|contract_id| Some(contract_id) != cursor.as_ref())
+            .take(limit.saturating_add(1))
+            .try_collect()?;
+        let has_more = contracts.len() > limit;
+        contracts.truncate(limit);
+        Ok((contracts, has_more))
+    }
+}
+
+/// Decodes the raw key of a table keyed by `ContractId`.
+fn contract_id_from_key(key: StorageResult<Vec<u8>>) -> StorageResult<ContractId> {
+    ContractId::try_from(key?.as_slice())
+        .map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))
+}
+
+/// Splits the raw key of a table keyed by `(ContractId, [u8; 32])`, like the state and
+/// balances of contracts, into its contract id and the rest of the key.
+fn split_contract_prefixed_key(key: &[u8]) -> StorageResult<(ContractId, [u8; 32])> {
+    let invalid_length = || {
+        StorageError::Codec(anyhow::anyhow!(
+            "Expected a contract prefixed key of 64 bytes, got {} bytes",
+            key.len()
+        ))
+    };
+    if key.len() != 64 {
+        return Err(invalid_length())
+    }
+    let (contract_id, suffix) = key.split_at(32);
+    let contract_id = <[u8; 32]>::try_from(contract_id).map_err(|_| invalid_length())?;
+    let suffix = <[u8; 32]>::try_from(suffix).map_err(|_| invalid_length())?;
+    Ok((contract_id.into(), suffix))
+}
+
+fn ensure_same_contract(
+    expected: ContractId,
+    found: ContractId,
+) -> Result<(), ContractDbError> {
+    if expected != found {
+        return Err(ContractDbError::MismatchedId { expected, found });
+    }
+    Ok(())
+}
+
+/// Checks that the `config` sets each state slot and each balance at most once.
+fn validate_contract_config(config: &ContractConfig) -> Result<(), ContractDbError> {
+    let contract_id = config.contract_id;
+    if let Some(state) = config.states.iter().duplicates_by(|state| state.key).next() {
+        return Err(ContractDbError::DuplicateStateKey {
+            contract_id,
+            key: state.key,
+        });
+    }
+    if let Some(balance) = config
+        .balances
+        .iter()
+        .duplicates_by(|balance| balance.asset_id)
+        .next()
+    {
+        return Err(ContractDbError::DuplicateBalance {
+            contract_id,
+            asset_id: balance.asset_id,
+        });
+    }
+    Ok(())
+}
+
+impl Database {
+    /// Copies every state slot and balance of the `from` contract to the `to` contract,
+    /// returning the number of copied entries. The code and the info of the contracts
+    /// are not copied.
+    pub fn clone_contract_storage(
+        &mut self,
+        from: ContractId,
+        to: ContractId,
+    ) -> StorageResult<u64> {
+        let states: Vec<_> = self
+            .iter_all_by_prefix::<ContractsState, _>(Some(from))
+            .try_collect()?;
+        let balances: Vec<_> = self
+            .iter_all_by_prefix::<ContractsAssets, _>(Some(from))
+            .try_collect()?;
+
+        let mut transaction = self.write_transaction();
+        for (key, value) in &states {
+            transaction
+                .storage_as_mut::<ContractsState>()
+                .insert(&ContractsStateKey::new(&to, key.state_key()), &value.0)?;
+        }
+        for (key, amount) in &balances {
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(&to, key.asset_id()), amount)?;
+        }
+        transaction.commit()?;
+
+        let copied = states.len().saturating_add(balances.len());
+        Ok(u64::try_from(copied).unwrap_or(u64::MAX))
+    }
+
+    /// Sets the state slot `key` of the contract to `value` and returns the previous value
+    /// of the slot, or `None` if it was unset.
+    pub fn set_contract_state(
+        &mut self,
+        contract_id: &ContractId,
+        key: &Bytes32,
+        value: &Bytes32,
+    ) -> StorageResult<Option<Bytes32>> {
+        let mut transaction = self.write_transaction();
+        let previous = transaction
+            .storage_as_mut::<ContractsState>()
+            .insert(&ContractsStateKey::new(contract_id, key), value.as_ref())?;
+        // The slot is only overwritten if its previous value is valid
+        let previous = previous
+            .map(|previous| {
+                Bytes32::try_from(previous.0.as_slice()).map_err(|_| {
+                    anyhow::anyhow!(
+                        "The previous value of the state slot {key} isn't 32 bytes long"
+                    )
+                })
+            })
+            .transpose()?;
+        transaction.commit()?;
+        Ok(previous)
+    }
+
+    /// Replaces the code of the contract with `new_code` and returns the previous code,
+    /// or `None` if the contract had no code. The state and the balances of the
+    /// contract are left untouched.
+    pub fn replace_contract_code(
+        &mut self,
+        contract_id: &ContractId,
+        new_code: &[u8],
+    ) -> StorageResult<Option<Contract>> {
+        self.storage_as_mut::<ContractsRawCode>()
+            .insert(contract_id, new_code)
+    }
+
+    /// Writes the code, the latest UTXO, the state and the balances of the contract
+    /// described by the `config` in one transaction.
+    pub fn insert_contract_config(
+        &mut self,
+        config: &ContractConfig,
+    ) -> StorageResult<()> {
+        let contract_id = config.contract_id;
+        let mut transaction = self.write_transaction();
+        transaction
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &config.code)?;
+        transaction.storage_as_mut::<ContractsLatestUtxo>().insert(
+            &contract_id,
+            &ContractUtxoInfo::V1((config.utxo_id(), config.tx_pointer()).into()),
+        )?;
+        transaction.update_contract_states(config.states.iter().map(|state| {
+            TableEntry {
+                key: ContractsStateKey::new(&contract_id, &state.key),
+                value: state.value.clone().into(),
+            }
+        }))?;
+        transaction.update_contract_balances(config.balances.iter().map(|balance| {
+            TableEntry {
+                key: ContractsAssetKey::new(&contract_id, &balance.asset_id),
+                value: balance.amount,
+            }
+        }))?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Validates and writes the contracts yielded by the `configs` stream, each in its
+    /// own transaction, and returns the number of contracts written. The import stops at
+    /// the first invalid config; the contracts written before it are kept.
+    pub async fn import_contract_configs(
+        &mut self,
+        configs: impl Stream<Item = ContractConfig>,
+    ) -> anyhow::Result<u64> {
+        let mut configs = std::pin::pin!(configs);
+        let mut imported = 0u64;
+        while let Some(config) = configs.next().await {
+            validate_contract_config(&config)?;
+            self.insert_contract_config(&config)?;
+            imported = imported.saturating_add(1);
+        }
+        Ok(imported)
+    }
+
+    /// Writes all state `entries` of the `contract` in one batch.
+    pub fn insert_contract_state_batch(
+        &mut self,
+        contract: ContractId,
+        entries: &[(Bytes32, Bytes32)],
+    ) -> StorageResult<()> {
+        self.update_contract_states(entries.iter().map(|(key, value)| TableEntry {
+            key: ContractsStateKey::new(&contract, key),
+            value: value.to_vec().into(),
+        }))
+    }
+}
+
+impl OffChainIterableKeyValueView {
+    /// Returns the salt of the contract, or `None` if the contract is unknown.
+    pub fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Option<Salt>> {
+        let salt = self
+            .storage::<ContractsInfo>()
+            .get(contract_id)?
+            .map(|info| *info.salt());
+        Ok(salt)
+    }
 }
 
+#[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::database::{
-        database_description::on_chain::OnChain,
-        Database,
+    use crate::{
+        combined_database::CombinedDatabase,
+        database::{
+            database_description::{
+                off_chain::OffChain,
+                on_chain::OnChain,
+            },
+            Error as DatabaseError,
+        },
+        state::iterable_key_value_view::IterableKeyValueViewWrapper,
+    };
+    use fuel_core_storage::{
+        iter::BoxedIter,
+        kv_store::{
+            KVItem,
+            KeyValueInspect,
+            KeyValueMutate,
+            Value,
+        },
+        transactional::AtomicView,
+    };
+    use fuel_core_types::{
+        entities::contract::ContractsInfoType,
+        fuel_tx::{
+            TxPointer,
+            UtxoId,
+        },
+        fuel_types::Bytes32,
     };
-    use fuel_core_storage::StorageAsMut;
-    use fuel_core_types::fuel_tx::Contract;
     use rand::{
         RngCore,
         SeedableRng,
     };
+    use std::sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    };
 
     #[test]
     fn raw_code_put_huge_contract() {
@@ -118,7 +824,7 @@ mod tests {
 
         let database = &mut Database::<OnChain>::default();
         database
-            .storage::<ContractsRawCode>()
+            .storage_as_mut::<ContractsRawCode>()
             .insert(&contract_id, contract.as_ref())
             .unwrap();
 
@@ -130,4 +836,1348 @@ mod tests {
             .into_owned();
         assert_eq!(returned, contract);
     }
+
+    #[test]
+    fn raw_code_put_rejects_contract_over_max_size() {
+        let contract_id: ContractId = ContractId::from([1u8; 32]);
+        let database =
+            &mut Database::<OnChain>::default().with_max_contract_code_size(Some(1024));
+
+        database
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[0; 1024])
+            .unwrap();
+        let result = database
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[1; 1025]);
+
+        assert_eq!(
+            result,
+            Err(StorageError::from(DatabaseError::ContractCodeTooLarge {
+                size: 1025,
+                limit: 1024,
+            }))
+        );
+        let stored = database
+            .storage::<ContractsRawCode>()
+            .get(&contract_id)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(stored, Contract::from(vec![0; 1024]));
+    }
+
+    fn database_with_contracts(ids: &[u8]) -> Database<OnChain> {
+        let mut database = Database::<OnChain>::default();
+        for id in ids {
+            database
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(&ContractId::from([*id; 32]), &[*id])
+                .unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn contracts_page__forward_pagination_follows_the_cursor() {
+        let database = database_with_contracts(&[1, 2, 3, 4, 5]);
+        let view = database.latest_view().unwrap();
+
+        let (first_page, has_more) = view
+            .contracts_page(None, 2, IterDirection::Forward)
+            .unwrap();
+        assert_eq!(
+            first_page,
+            vec![ContractId::from([1; 32]), ContractId::from([2; 32])]
+        );
+        assert!(has_more);
+
+        let (second_page, has_more) = view
+            .contracts_page(first_page.last().copied(), 2, IterDirection::Forward)
+            .unwrap();
+        assert_eq!(
+            second_page,
+            vec![ContractId::from([3; 32]), ContractId::from([4; 32])]
+        );
+        assert!(has_more);
+    }
+
+    #[test]
+    fn contracts_page__backward_pagination_follows_the_cursor() {
+        let database = database_with_contracts(&[1, 2, 3, 4, 5]);
+        let view = database.latest_view().unwrap();
+
+        let (page, has_more) = view
+            .contracts_page(Some(ContractId::from([4; 32])), 2, IterDirection::Reverse)
+            .unwrap();
+
+        assert_eq!(
+            page,
+            vec![ContractId::from([3; 32]), ContractId::from([2; 32])]
+        );
+        assert!(has_more);
+    }
+
+    #[test]
+    fn contracts_page__final_page_has_no_more() {
+        let database = database_with_contracts(&[1, 2, 3]);
+        let view = database.latest_view().unwrap();
+
+        let (page, has_more) = view
+            .contracts_page(Some(ContractId::from([1; 32])), 2, IterDirection::Forward)
+            .unwrap();
+
+        assert_eq!(
+            page,
+            vec![ContractId::from([2; 32]), ContractId::from([3; 32])]
+        );
+        assert!(!has_more);
+    }
+
+    fn database_with_balances(
+        balances: &[(ContractId, AssetId, Word)],
+    ) -> Database<OnChain> {
+        let mut database = Database::<OnChain>::default();
+        for (contract, asset, balance) in balances {
+            database
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(contract, asset), balance)
+                .unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn contract_asset_balance__returns_balance_of_present_asset() {
+        let contract = ContractId::from([1; 32]);
+        let asset = AssetId::from([2; 32]);
+        let database = database_with_balances(&[(contract, asset, 100)]);
+        let view = database.latest_view().unwrap();
+
+        let balance = view.contract_asset_balance(contract, asset).unwrap();
+
+        assert_eq!(balance, 100);
+    }
+
+    #[test]
+    fn contract_asset_balance__returns_zero_for_absent_asset() {
+        let contract = ContractId::from([1; 32]);
+        let database = database_with_balances(&[(contract, AssetId::from([2; 32]), 100)]);
+        let view = database.latest_view().unwrap();
+
+        let balance = view
+            .contract_asset_balance(contract, AssetId::from([3; 32]))
+            .unwrap();
+
+        assert_eq!(balance, 0);
+    }
+
+    #[test]
+    fn contract_asset_balance__does_not_leak_other_contracts_balance() {
+        let asset = AssetId::from([2; 32]);
+        let database = database_with_balances(&[(ContractId::from([1; 32]), asset, 100)]);
+        let view = database.latest_view().unwrap();
+
+        let balance = view
+            .contract_asset_balance(ContractId::from([3; 32]), asset)
+            .unwrap();
+
+        assert_eq!(balance, 0);
+    }
+
+    fn balances_of(
+        view: &OnChainIterableKeyValueView,
+        contract: ContractId,
+        start_asset: Bound<AssetId>,
+        direction: IterDirection,
+    ) -> Vec<AssetId> {
+        view.filter_contract_balances(contract, start_asset, Some(direction))
+            .map_ok(|entry| *entry.key.asset_id())
+            .try_collect()
+            .unwrap()
+    }
+
+    #[test]
+    fn filter_contract_balances__included_start_yields_the_boundary_asset() {
+        let contract = ContractId::from([1; 32]);
+        let assets = [1u8, 2, 3].map(|byte| AssetId::from([byte; 32]));
+        let database = database_with_balances(&assets.map(|asset| (contract, asset, 10)));
+        let view = database.latest_view().unwrap();
+
+        let forward = balances_of(
+            &view,
+            contract,
+            Bound::Included(assets[1]),
+            IterDirection::Forward,
+        );
+        let reverse = balances_of(
+            &view,
+            contract,
+            Bound::Included(assets[1]),
+            IterDirection::Reverse,
+        );
+
+        assert_eq!(forward, vec![assets[1], assets[2]]);
+        assert_eq!(reverse, vec![assets[1], assets[0]]);
+    }
+
+    #[test]
+    fn filter_contract_balances__excluded_start_skips_the_boundary_asset() {
+        let contract = ContractId::from([1; 32]);
+        let assets = [1u8, 2, 3].map(|byte| AssetId::from([byte; 32]));
+        let database = database_with_balances(&assets.map(|asset| (contract, asset, 10)));
+        let view = database.latest_view().unwrap();
+
+        let forward = balances_of(
+            &view,
+            contract,
+            Bound::Excluded(assets[1]),
+            IterDirection::Forward,
+        );
+        let reverse = balances_of(
+            &view,
+            contract,
+            Bound::Excluded(assets[1]),
+            IterDirection::Reverse,
+        );
+
+        assert_eq!(forward, vec![assets[2]]);
+        assert_eq!(reverse, vec![assets[0]]);
+    }
+
+    #[test]
+    fn filter_contract_balances__unbounded_start_yields_all_assets() {
+        let contract = ContractId::from([1; 32]);
+        let assets = [1u8, 2, 3].map(|byte| AssetId::from([byte; 32]));
+        let database = database_with_balances(&assets.map(|asset| (contract, asset, 10)));
+        let view = database.latest_view().unwrap();
+
+        let forward =
+            balances_of(&view, contract, Bound::Unbounded, IterDirection::Forward);
+
+        assert_eq!(forward, assets.to_vec());
+    }
+
+    #[test]
+    fn filter_contract_balances__malformed_key_is_an_error() {
+        let contract = ContractId::from([1; 32]);
+        let mut database = Database::<OnChain>::default();
+        let mut transaction = database.write_transaction();
+        let short_key = [contract.as_ref(), &[2; 8]].concat();
+        transaction
+            .put(
+                &short_key,
+                Column::ContractsAssets,
+                std::sync::Arc::new(100u64.to_be_bytes().to_vec()),
+            )
+            .unwrap();
+        transaction.commit().unwrap();
+        let view = database.latest_view().unwrap();
+
+        let result = view
+            .filter_contract_balances(contract, Bound::Unbounded, None)
+            .next()
+            .expect("The malformed entry should be visited");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn contract_salt__returns_salt_of_present_contract() {
+        let contract_id = ContractId::from([1; 32]);
+        let salt = Salt::new([2; 32]);
+        let mut database = Database::<OffChain>::default();
+        database
+            .storage_as_mut::<ContractsInfo>()
+            .insert(&contract_id, &ContractsInfoType::V1(salt.into()))
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let result = view.contract_salt(&contract_id).unwrap();
+
+        assert_eq!(result, Some(salt));
+    }
+
+    #[test]
+    fn contract_salt__returns_none_for_absent_contract() {
+        let database = Database::<OffChain>::default();
+        let view = database.latest_view().unwrap();
+
+        let result = view.contract_salt(&ContractId::from([1; 32])).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn contract_code_and_salt__returns_both_for_present_contract() {
+        let contract_id = ContractId::from([1; 32]);
+        let salt = Salt::new([2; 32]);
+        let mut database = CombinedDatabase::default();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[3; 8])
+            .unwrap();
+        database
+            .off_chain_mut()
+            .storage_as_mut::<ContractsInfo>()
+            .insert(&contract_id, &ContractsInfoType::V1(salt.into()))
+            .unwrap();
+
+        let result = database.contract_code_and_salt(&contract_id).unwrap();
+
+        assert_eq!(result, Some((Contract::from(vec![3; 8]), salt)));
+    }
+
+    #[test]
+    fn contract_code_and_salt__returns_none_without_info() {
+        let contract_id = ContractId::from([1; 32]);
+        let mut database = CombinedDatabase::default();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[3; 8])
+            .unwrap();
+
+        let result = database.contract_code_and_salt(&contract_id).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn contract_latest_utxo__returns_inserted_utxo() {
+        let contract_id = ContractId::from([1; 32]);
+        let utxo = ContractUtxoInfo::V1(
+            (UtxoId::new([2; 32].into(), 3), TxPointer::new(4.into(), 5)).into(),
+        );
+        let mut database = Database::<OnChain>::default();
+        database
+            .storage_as_mut::<ContractsLatestUtxo>()
+            .insert(&contract_id, &utxo)
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let result = view.contract_latest_utxo(&contract_id).unwrap();
+
+        assert_eq!(result, Some(utxo));
+    }
+
+    #[test]
+    fn contract_latest_utxo__returns_none_for_absent_contract() {
+        let database = Database::<OnChain>::default();
+        let view = database.latest_view().unwrap();
+
+        let result = view
+            .contract_latest_utxo(&ContractId::from([1; 32]))
+            .unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    fn database_with_deployed_contracts(codes: &[(u8, Vec<u8>)]) -> Database<OnChain> {
+        let mut database = Database::<OnChain>::default();
+        for (id, code) in codes {
+            let contract_id = ContractId::from([*id; 32]);
+            database
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(&contract_id, code)
+                .unwrap();
+            database
+                .storage_as_mut::<ContractsLatestUtxo>()
+                .insert(&contract_id, &ContractUtxoInfo::default())
+                .unwrap();
+        }
+        database
+    }
+
+    fn given_contract_config(id: u8) -> ContractConfig {
+        ContractConfig {
+            contract_id: ContractId::from([id; 32]),
+            code: vec![id; 8],
+            tx_id: Bytes32::from([id; 32]),
+            output_index: u16::from(id),
+            tx_pointer_block_height: BlockHeight::from(u32::from(id)),
+            tx_pointer_tx_idx: u16::from(id),
+            states: vec![ContractStateConfig {
+                key: Bytes32::from([id; 32]),
+                value: vec![id; 32],
+            }],
+            balances: vec![ContractBalanceConfig {
+                asset_id: AssetId::from([id; 32]),
+                amount: u64::from(id),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn import_contract_configs__streamed_configs_can_be_read_back() {
+        let configs: Vec<_> = (1..=3).map(given_contract_config).collect();
+        let mut database = Database::<OnChain>::default();
+
+        let imported = database
+            .import_contract_configs(futures::stream::iter(configs.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(imported, 3);
+        let view = database.latest_view().unwrap();
+        for config in configs {
+            assert_eq!(
+                view.get_contract_config_by_id(config.contract_id).unwrap(),
+                config
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn import_contract_configs__stops_at_the_first_invalid_config() {
+        let mut invalid = given_contract_config(2);
+        invalid.states.push(invalid.states[0].clone());
+        let configs = vec![given_contract_config(1), invalid, given_contract_config(3)];
+        let mut database = Database::<OnChain>::default();
+
+        let result = database
+            .import_contract_configs(futures::stream::iter(configs))
+            .await;
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ContractDbError>(),
+            Some(&ContractDbError::DuplicateStateKey {
+                contract_id: ContractId::from([2; 32]),
+                key: Bytes32::from([2; 32]),
+            })
+        );
+        let view = database.latest_view().unwrap();
+        assert_eq!(view.contract_count().unwrap(), 1);
+    }
+
+    #[test]
+    fn contract_code_size_histogram__counts_contracts_per_size_bucket() {
+        let database = database_with_deployed_contracts(&[
+            (1, vec![1; 10]),
+            (2, vec![2; 100]),
+            (3, vec![3; 101]),
+            (4, vec![4; 500]),
+            (5, vec![5; 5000]),
+        ]);
+        let view = database.latest_view().unwrap();
+
+        let histogram = view.contract_code_size_histogram(&[100, 1000]).unwrap();
+
+        assert_eq!(histogram, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn contract_operations_are_counted_per_column() {
+        use fuel_core_metrics::core_metrics::{
+            database_metrics,
+            ColumnOperation,
+        };
+        use fuel_core_storage::{
+            kv_store::StorageColumn,
+            StorageRead,
+        };
+        let count = |column: Column, operation| {
+            database_metrics().column_operations_count(column.name(), operation)
+        };
+        let code_gets = count(Column::ContractsRawCode, ColumnOperation::Get);
+        let code_inserts = count(Column::ContractsRawCode, ColumnOperation::Insert);
+        let code_removes = count(Column::ContractsRawCode, ColumnOperation::Remove);
+        let state_inserts = count(Column::ContractsState, ColumnOperation::Insert);
+        let contract_id = ContractId::from([1; 32]);
+        let mut database = Database::<OnChain>::default().with_contract_metrics();
+
+        database
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[1; 4])
+            .unwrap();
+        database
+            .storage::<ContractsRawCode>()
+            .get(&contract_id)
+            .unwrap();
+        database
+            .storage::<ContractsRawCode>()
+            .contains_key(&contract_id)
+            .unwrap();
+        let mut buf = [0; 4];
+        StorageRead::<ContractsRawCode>::read(&database, &contract_id, &mut buf).unwrap();
+        let mut transaction = database.write_transaction();
+        for slot in 0..3u8 {
+            transaction
+                .storage_as_mut::<ContractsState>()
+                .insert(
+                    &ContractsStateKey::new(&contract_id, &Bytes32::from([slot; 32])),
+                    &[slot; 32],
+                )
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+        database
+            .storage_as_mut::<ContractsRawCode>()
+            .remove(&contract_id)
+            .unwrap();
+
+        // Other tests may operate on the same columns concurrently
+        assert!(
+            count(Column::ContractsRawCode, ColumnOperation::Get)
+                >= code_gets.saturating_add(3)
+        );
+        assert!(
+            count(Column::ContractsRawCode, ColumnOperation::Insert)
+                >= code_inserts.saturating_add(1)
+        );
+        assert!(
+            count(Column::ContractsRawCode, ColumnOperation::Remove)
+                >= code_removes.saturating_add(1)
+        );
+        assert!(
+            count(Column::ContractsState, ColumnOperation::Insert)
+                >= state_inserts.saturating_add(3)
+        );
+    }
+
+    #[test]
+    fn set_contract_state__returns_the_previous_value_of_the_slot() {
+        let contract_id = ContractId::from([1; 32]);
+        let key = Bytes32::from([2; 32]);
+        let first = Bytes32::from([3; 32]);
+        let second = Bytes32::from([4; 32]);
+        let mut database = Database::<OnChain>::default();
+
+        let unset = database
+            .set_contract_state(&contract_id, &key, &first)
+            .unwrap();
+        let previous = database
+            .set_contract_state(&contract_id, &key, &second)
+            .unwrap();
+
+        assert_eq!(unset, None);
+        assert_eq!(previous, Some(first));
+        let current = database
+            .storage::<ContractsState>()
+            .get(&ContractsStateKey::new(&contract_id, &key))
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(current.0, second.to_vec());
+    }
+
+    #[test]
+    fn set_contract_state__keeps_the_slot_when_the_previous_value_is_invalid() {
+        let contract_id = ContractId::from([1; 32]);
+        let key = Bytes32::from([2; 32]);
+        let slot = ContractsStateKey::new(&contract_id, &key);
+        let mut database = Database::<OnChain>::default();
+        database
+            .storage_as_mut::<ContractsState>()
+            .insert(&slot, &[3; 4])
+            .unwrap();
+
+        let result =
+            database.set_contract_state(&contract_id, &key, &Bytes32::from([4; 32]));
+
+        assert!(result.is_err());
+        let current = database
+            .storage::<ContractsState>()
+            .get(&slot)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(current.0, vec![3; 4]);
+    }
+
+    #[test]
+    fn replace_contract_code__keeps_the_state_and_balances() {
+        let contract_id = ContractId::from([1; 32]);
+        let old_code = vec![1; 16];
+        let key = Bytes32::from([2; 32]);
+        let value = Bytes32::from([3; 32]);
+        let asset_id = AssetId::from([4; 32]);
+        let mut database = database_with_deployed_contracts(&[(1, old_code.clone())]);
+        database
+            .set_contract_state(&contract_id, &key, &value)
+            .unwrap();
+        database
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &100)
+            .unwrap();
+
+        let replaced = database
+            .replace_contract_code(&contract_id, &[5; 32])
+            .unwrap();
+
+        assert_eq!(replaced, Some(Contract::from(old_code)));
+        let view = database.latest_view().unwrap();
+        assert_eq!(
+            Vec::<u8>::from(view.contract_code(contract_id).unwrap().value),
+            vec![5; 32]
+        );
+        assert!(view.contract_state_contains(&contract_id, &key).unwrap());
+        assert_eq!(
+            view.contract_asset_balance(contract_id, asset_id).unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn contracts_exist__flags_present_contracts_in_input_order() {
+        let database =
+            database_with_deployed_contracts(&[(1, vec![1; 4]), (3, vec![3; 4])]);
+        let view = database.latest_view().unwrap();
+        let ids = [3, 2, 1, 4, 1].map(|id| ContractId::from([id; 32]));
+
+        let exist = view.contracts_exist(&ids).unwrap();
+
+        assert_eq!(exist, vec![true, false, true, false, true]);
+    }
+
+    #[test]
+    fn contract_state_contains__only_finds_the_slot_of_the_given_contract() {
+        let contract_id = ContractId::from([1; 32]);
+        let other_contract_id = ContractId::from([2; 32]);
+        let set_key = Bytes32::from([3; 32]);
+        let unset_key = Bytes32::from([4; 32]);
+        let mut database = Database::<OnChain>::default();
+        database
+            .set_contract_state(&contract_id, &set_key, &Bytes32::from([5; 32]))
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let set = view
+            .contract_state_contains(&contract_id, &set_key)
+            .unwrap();
+        let unset = view
+            .contract_state_contains(&contract_id, &unset_key)
+            .unwrap();
+        let other_contract = view
+            .contract_state_contains(&other_contract_id, &set_key)
+            .unwrap();
+
+        assert!(set);
+        assert!(!unset);
+        assert!(!other_contract);
+    }
+
+    #[test]
+    fn contract_configs_iter__yields_the_same_configs_as_the_eager_read() {
+        let mut database = database_with_deployed_contracts(&[
+            (1, vec![1; 4]),
+            (2, vec![2; 8]),
+            (3, vec![3; 16]),
+        ]);
+        database
+            .set_contract_state(
+                &ContractId::from([2; 32]),
+                &Bytes32::from([4; 32]),
+                &Bytes32::from([5; 32]),
+            )
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let streamed: Vec<_> = view.contract_configs_iter().try_collect().unwrap();
+
+        let eager = view
+            .get_contract_configs_where(IterDirection::Forward, |_| true)
+            .unwrap();
+        assert_eq!(streamed.len(), 3);
+        assert_eq!(streamed, eager);
+    }
+
+    #[test]
+    fn get_contract_configs_where__filters_on_code_size() {
+        let database = database_with_deployed_contracts(&[
+            (1, vec![1; 4]),
+            (2, vec![2; 64]),
+            (3, vec![3; 128]),
+        ]);
+        let view = database.latest_view().unwrap();
+
+        let configs = view
+            .get_contract_configs_where(IterDirection::Forward, |contract_id| {
+                view.contract_code_len(contract_id)
+                    .unwrap()
+                    .unwrap_or_default()
+                    > 32
+            })
+            .unwrap();
+
+        let contract_ids: Vec<_> = configs.iter().map(|c| c.contract_id).collect();
+        assert_eq!(
+            contract_ids,
+            vec![ContractId::from([2; 32]), ContractId::from([3; 32])]
+        );
+        assert_eq!(configs[0].code, vec![2; 64]);
+    }
+
+    #[test]
+    fn get_contract_configs_where__configs_are_ordered_by_id() {
+        let database = database_with_deployed_contracts(&[
+            (3, vec![3; 4]),
+            (1, vec![1; 4]),
+            (4, vec![4; 4]),
+            (2, vec![2; 4]),
+        ]);
+        let view = database.latest_view().unwrap();
+        let ids = |direction| -> Vec<u8> {
+            view.get_contract_configs_where(direction, |_| true)
+                .unwrap()
+                .iter()
+                .map(|config| config.contract_id[0])
+                .collect()
+        };
+
+        assert_eq!(ids(IterDirection::Forward), vec![1, 2, 3, 4]);
+        assert_eq!(ids(IterDirection::Reverse), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn get_contract_configs_where__includes_state_and_balances() {
+        let contract_id = ContractId::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let mut database = database_with_deployed_contracts(&[(1, vec![1; 4])]);
+        database
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &100)
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let configs = view
+            .get_contract_configs_where(IterDirection::Forward, |_| true)
+            .unwrap();
+
+        assert_eq!(
+            configs[0].balances,
+            vec![ContractBalanceConfig {
+                asset_id,
+                amount: 100
+            }]
+        );
+        assert!(configs[0].states.is_empty());
+    }
+
+    #[test]
+    fn get_contract_config_by_id__reads_from_a_single_snapshot() {
+        let contract_id = ContractId::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let slot = Bytes32::from([3; 32]);
+        let mut database = database_with_deployed_contracts(&[(1, vec![1; 4])]);
+        database
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &100)
+            .unwrap();
+        let view = database.latest_view().unwrap();
+        let expected = view.get_contract_config_by_id(contract_id).unwrap();
+
+        // Modify every table read by the config after the view was taken.
+        database
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[9; 16])
+            .unwrap();
+        database
+            .storage_as_mut::<ContractsLatestUtxo>()
+            .insert(
+                &contract_id,
+                &ContractUtxoInfo::V1(
+                    (UtxoId::new([5; 32].into(), 1), TxPointer::new(7.into(), 3)).into(),
+                ),
+            )
+            .unwrap();
+        database
+            .storage_as_mut::<ContractsState>()
+            .insert(&ContractsStateKey::new(&contract_id, &slot), &[4; 32])
+            .unwrap();
+        database
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &200)
+            .unwrap();
+
+        let config = view.get_contract_config_by_id(contract_id).unwrap();
+
+        assert_eq!(config, expected);
+        assert_eq!(config.code, vec![1; 4]);
+        assert!(config.states.is_empty());
+        assert_eq!(config.balances[0].amount, 100);
+        let latest = database
+            .latest_view()
+            .unwrap()
+            .get_contract_config_by_id(contract_id)
+            .unwrap();
+        assert_eq!(latest.balances[0].amount, 200);
+        assert_eq!(latest.states.len(), 1);
+    }
+
+    #[test]
+    fn get_contract_config_by_id__fails_for_unknown_contract() {
+        let database = Database::<OnChain>::default();
+        let view = database.latest_view().unwrap();
+
+        let result = view.get_contract_config_by_id(ContractId::from([1; 32]));
+
+        assert!(result.is_err());
+    }
+
+    fn read_exported_state(
+        contract_id: &ContractId,
+        mut bytes: &[u8],
+    ) -> Vec<TableEntry<ContractsState>> {
+        let mut entries = vec![];
+        while !bytes.is_empty() {
+            let (key, rest) = bytes.split_at(32);
+            let (len, rest) = rest.split_at(4);
+            let len =
+                usize::try_from(u32::from_be_bytes(len.try_into().unwrap())).unwrap();
+            let (value, rest) = rest.split_at(len);
+            entries.push(TableEntry {
+                key: ContractsStateKey::new(
+                    contract_id,
+                    &Bytes32::try_from(key).unwrap(),
+                ),
+                value: value.to_vec().into(),
+            });
+            bytes = rest;
+        }
+        entries
+    }
+
+    #[test]
+    fn export_contract_state__round_trips_through_the_stream() {
+        let contract_id = ContractId::from([1; 32]);
+        let other_id = ContractId::from([2; 32]);
+        let mut database = Database::<OnChain>::default();
+        let state = vec![
+            (Bytes32::from([1; 32]), vec![1; 32]),
+            (Bytes32::from([2; 32]), vec![2; 7]),
+            (Bytes32::from([3; 32]), vec![]),
+        ];
+        database
+            .update_contract_states(state.iter().map(|(key, value)| TableEntry {
+                key: ContractsStateKey::new(&contract_id, key),
+                value: value.clone().into(),
+            }))
+            .unwrap();
+        database
+            .insert_contract_state_batch(other_id, &[([4; 32].into(), [4; 32].into())])
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let mut stream = vec![];
+        let written = view
+            .export_contract_state(contract_id, &mut stream)
+            .unwrap();
+        let mut imported = Database::<OnChain>::default();
+        imported
+            .update_contract_states(read_exported_state(&contract_id, &stream))
+            .unwrap();
+
+        assert_eq!(written, 3);
+        let exported: Vec<_> = imported
+            .latest_view()
+            .unwrap()
+            .iter_contract_state()
+            .map(|entry| entry.unwrap())
+            .collect();
+        let expected: Vec<_> = view
+            .iter_all_by_prefix::<ContractsState, _>(Some(contract_id))
+            .map(|entry| {
+                let (key, value) = entry.unwrap();
+                TableEntry { key, value }
+            })
+            .collect();
+        assert_eq!(exported, expected);
+    }
+
+    #[derive(Debug)]
+    struct CodeReadCounter {
+        inner: OnChainIterableKeyValueView,
+        code_reads: Arc<AtomicUsize>,
+    }
+
+    impl KeyValueInspect for CodeReadCounter {
+        type Column = Column;
+
+        fn get(&self, key: &[u8], column: Column) -> StorageResult<Option<Value>> {
+            if column == Column::ContractsRawCode {
+                self.code_reads.fetch_add(1, Ordering::SeqCst);
+            }
+            KeyValueInspect::get(&self.inner, key, column)
+        }
+    }
+
+    impl IterableStore for CodeReadCounter {
+        fn iter_store(
+            &self,
+            column: Column,
+            prefix: Option<&[u8]>,
+            start: Option<&[u8]>,
+            direction: IterDirection,
+        ) -> BoxedIter<KVItem> {
+            IterableStore::iter_store(&self.inner, column, prefix, start, direction)
+        }
+    }
+
+    #[test]
+    fn get_contract_configs__deduplicates_ids_in_first_seen_order() {
+        let a = ContractId::from([2; 32]);
+        let b = ContractId::from([1; 32]);
+        let database =
+            database_with_deployed_contracts(&[(1, vec![1; 4]), (2, vec![2; 4])]);
+        let code_reads = Arc::new(AtomicUsize::new(0));
+        let view = OnChainIterableKeyValueView::from_storage(
+            IterableKeyValueViewWrapper::new(CodeReadCounter {
+                inner: database.latest_view().unwrap(),
+                code_reads: code_reads.clone(),
+            }),
+        );
+
+        let configs = view.get_contract_configs(&[a, b, a]).unwrap();
+
+        let ids: Vec<_> = configs.iter().map(|config| config.contract_id).collect();
+        assert_eq!(ids, vec![a, b]);
+        assert_eq!(code_reads.load(Ordering::SeqCst), 2);
+    }
+
+    fn contract_db_error(error: StorageError) -> ContractDbError {
+        match error {
+            StorageError::Other(e) => e
+                .downcast::<ContractDbError>()
+                .expect("Expected a contract database error"),
+            e => panic!("Expected a contract database error, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn get_contract_config_by_id__missing_info_is_reported() {
+        let contract_id = ContractId::from([1; 32]);
+        let mut database = Database::<OnChain>::default();
+        database
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[1, 2, 3])
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let error = view.get_contract_config_by_id(contract_id).unwrap_err();
+
+        assert_eq!(
+            contract_db_error(error),
+            ContractDbError::MissingInfo { contract_id }
+        );
+    }
+
+    #[test]
+    fn get_contract_config_by_id__malformed_state_key_is_reported() {
+        let contract_id = ContractId::from([1; 32]);
+        let mut database = database_with_deployed_contracts(&[(1, vec![1, 2, 3])]);
+        let mut transaction = database.write_transaction();
+        let short_key = [contract_id.as_ref(), &[2; 8]].concat();
+        transaction
+            .put(
+                &short_key,
+                Column::ContractsState,
+                std::sync::Arc::new(vec![3; 32]),
+            )
+            .unwrap();
+        transaction.commit().unwrap();
+        let view = database.latest_view().unwrap();
+
+        let error = view.get_contract_config_by_id(contract_id).unwrap_err();
+
+        assert_eq!(
+            contract_db_error(error),
+            ContractDbError::MalformedStateKey { contract_id }
+        );
+    }
+
+    #[test]
+    fn split_contract_prefixed_key__splits_a_valid_key() {
+        let key = [[1; 32], [2; 32]].concat();
+
+        let (contract_id, suffix) = split_contract_prefixed_key(&key).unwrap();
+
+        assert_eq!(contract_id, ContractId::from([1; 32]));
+        assert_eq!(suffix, [2; 32]);
+    }
+
+    #[test]
+    fn split_contract_prefixed_key__too_short_key_is_an_error() {
+        let key = [[1; 32].as_slice(), &[2; 8]].concat();
+
+        let result = split_contract_prefixed_key(&key);
+
+        assert!(matches!(result, Err(StorageError::Codec(_))));
+    }
+
+    #[test]
+    fn split_contract_prefixed_key__contract_id_alone_is_an_error() {
+        let key = [1; 32];
+
+        let result = split_contract_prefixed_key(&key);
+
+        assert!(matches!(result, Err(StorageError::Codec(_))));
+    }
+
+    #[test]
+    fn contract_summary__malformed_balance_key_is_an_error() {
+        let contract_id = ContractId::from([1; 32]);
+        let mut database = database_with_deployed_contracts(&[(1, vec![1; 4])]);
+        let mut transaction = database.write_transaction();
+        let short_key = [contract_id.as_ref(), &[2; 8]].concat();
+        transaction
+            .put(
+                &short_key,
+                Column::ContractsAssets,
+                std::sync::Arc::new(100u64.to_be_bytes().to_vec()),
+            )
+            .unwrap();
+        transaction.commit().unwrap();
+        let view = database.latest_view().unwrap();
+
+        let result = view.contract_summary(contract_id);
+
+        assert!(matches!(result, Err(StorageError::Codec(_))));
+    }
+
+    #[test]
+    fn ensure_same_contract__mismatched_id_is_reported() {
+        let expected = ContractId::from([1; 32]);
+        let found = ContractId::from([2; 32]);
+
+        let error = ensure_same_contract(expected, found).unwrap_err();
+
+        assert_eq!(error, ContractDbError::MismatchedId { expected, found });
+        assert!(ensure_same_contract(expected, expected).is_ok());
+    }
+
+    #[test]
+    fn clone_contract_storage__copies_state_and_balances_but_not_code() {
+        let from = ContractId::from([1; 32]);
+        let to = ContractId::from([2; 32]);
+        let slots = [Bytes32::from([3; 32]), Bytes32::from([4; 32])];
+        let asset = AssetId::from([5; 32]);
+        let mut database = database_with_deployed_contracts(&[(1, vec![6; 8])]);
+        for slot in &slots {
+            database
+                .storage_as_mut::<ContractsState>()
+                .insert(&ContractsStateKey::new(&from, slot), &[7; 32])
+                .unwrap();
+        }
+        database
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&from, &asset), &100)
+            .unwrap();
+
+        let copied = database.clone_contract_storage(from, to).unwrap();
+
+        assert_eq!(copied, 3);
+        let view = database.latest_view().unwrap();
+        let state_of = |contract: ContractId| -> Vec<(Bytes32, Vec<u8>)> {
+            view.iter_all_by_prefix::<ContractsState, _>(Some(contract))
+                .map_ok(|(key, value)| (*key.state_key(), value.0))
+                .try_collect()
+                .unwrap()
+        };
+        assert_eq!(state_of(to), state_of(from));
+        assert_eq!(state_of(from).len(), 2);
+        assert_eq!(view.contract_asset_balance(to, asset).unwrap(), 100);
+        assert_eq!(view.contract_asset_balance(from, asset).unwrap(), 100);
+        assert_eq!(view.contract_code_len(&to).unwrap(), None);
+    }
+
+    #[test]
+    fn insert_contract_state_batch__slots_are_readable_in_key_order() {
+        let rng = &mut rand::rngs::StdRng::seed_from_u64(2322u64);
+        let contract = ContractId::from([1; 32]);
+        let mut entries: Vec<(Bytes32, Bytes32)> = (0..1000)
+            .map(|_| {
+                let mut key = [0u8; 32];
+                let mut value = [0u8; 32];
+                rng.fill_bytes(&mut key);
+                rng.fill_bytes(&mut value);
+                (key.into(), value.into())
+            })
+            .collect();
+        let mut database = Database::<OnChain>::default();
+
+        database
+            .insert_contract_state_batch(contract, &entries)
+            .unwrap();
+
+        let view = database.latest_view().unwrap();
+        let stored: Vec<(Bytes32, Bytes32)> = view
+            .iter_contract_state()
+            .map_ok(|entry| {
+                assert_eq!(entry.key.contract_id(), &contract);
+                let value = Bytes32::try_from(entry.value.0.as_slice()).unwrap();
+                (*entry.key.state_key(), value)
+            })
+            .try_collect()
+            .unwrap();
+        entries.sort();
+        assert_eq!(stored, entries);
+    }
+
+    #[test]
+    fn contract_count__counts_deployed_contracts() {
+        let database = database_with_deployed_contracts(&[
+            (1, vec![1; 8]),
+            (2, vec![2; 16]),
+            (3, vec![3; 32]),
+        ]);
+        let view = database.latest_view().unwrap();
+
+        let count = view.contract_count().unwrap();
+
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn iter_keys_only__yields_the_ids_of_contracts_with_large_code() {
+        let large_code = vec![0xFF; 1024 * 1024];
+        let database = database_with_deployed_contracts(&[
+            (1, large_code.clone()),
+            (2, large_code.clone()),
+            (3, large_code),
+        ]);
+
+        let keys: Vec<_> = database
+            .iter_keys_only(Column::ContractsRawCode, None, IterDirection::Reverse)
+            .try_collect()
+            .unwrap();
+
+        let expected: Vec<_> = [3u8, 2, 1].map(|id| vec![id; 32]).to_vec();
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn contract_summary__counts_match_the_populated_contract() {
+        let contract_id = ContractId::from([1; 32]);
+        let code = vec![0xFF; 1024 * 1024];
+        let mut database = database_with_deployed_contracts(&[(1, code.clone())]);
+        database
+            .insert_contract_state_batch(
+                contract_id,
+                &[
+                    (Bytes32::from([1; 32]), Bytes32::from([2; 32])),
+                    (Bytes32::from([3; 32]), Bytes32::from([4; 32])),
+                    (Bytes32::from([5; 32]), Bytes32::from([6; 32])),
+                ],
+            )
+            .unwrap();
+        database
+            .storage_as_mut::<ContractsAssets>()
+            .insert(
+                &ContractsAssetKey::new(&contract_id, &AssetId::from([7; 32])),
+                &100,
+            )
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let summary = view.contract_summary(contract_id).unwrap();
+
+        let config = view.get_contract_config_by_id(contract_id).unwrap();
+        assert_eq!(summary.contract_id, contract_id);
+        assert_eq!(summary.code_size, code.len());
+        assert_eq!(summary.state_count, 3);
+        assert_eq!(summary.state_count, config.states.len() as u64);
+        assert_eq!(summary.balance_count, 1);
+        assert_eq!(summary.balance_count, config.balances.len() as u64);
+        assert_eq!(
+            summary.tx_pointer_block_height,
+            config.tx_pointer_block_height
+        );
+        assert_eq!(summary.tx_pointer_tx_idx, config.tx_pointer_tx_idx);
+    }
+
+    #[test]
+    fn contract_summary__fails_for_unknown_contract() {
+        let database = Database::<OnChain>::default();
+        let view = database.latest_view().unwrap();
+
+        let result = view.contract_summary(ContractId::from([1; 32]));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn contract_count__is_zero_for_empty_database() {
+        let database = Database::<OnChain>::default();
+        let view = database.latest_view().unwrap();
+
+        let count = view.contract_count().unwrap();
+
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn all_contract_assets__sums_balances_across_contracts() {
+        let mut database = Database::<OnChain>::default();
+        let first = ContractId::from([1u8; 32]);
+        let second = ContractId::from([2u8; 32]);
+        let shared_asset = AssetId::new([1u8; 32]);
+        let first_only_asset = AssetId::new([2u8; 32]);
+        let second_only_asset = AssetId::new([3u8; 32]);
+        for (contract, asset, balance) in [
+            (first, shared_asset, 100),
+            (first, first_only_asset, 5),
+            (second, shared_asset, 50),
+            (second, second_only_asset, 7),
+        ] {
+            database
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(&contract, &asset), &balance)
+                .unwrap();
+        }
+        let view = database.latest_view().unwrap();
+
+        let totals = view.all_contract_assets().unwrap();
+
+        assert_eq!(
+            totals,
+            HashMap::from([
+                (shared_asset, 150),
+                (first_only_asset, 5),
+                (second_only_asset, 7),
+            ])
+        );
+    }
+
+    #[test]
+    fn top_contract_holders__returns_the_largest_balances_of_the_asset() {
+        let mut database = Database::<OnChain>::default();
+        let asset = AssetId::new([1u8; 32]);
+        let other_asset = AssetId::new([2u8; 32]);
+        for (contract, asset, balance) in [
+            (1u8, asset, 10),
+            (2, asset, 50),
+            (3, asset, 30),
+            (4, asset, 50),
+            (5, asset, 5),
+            (6, other_asset, 1_000),
+        ] {
+            database
+                .storage_as_mut::<ContractsAssets>()
+                .insert(
+                    &ContractsAssetKey::new(&ContractId::from([contract; 32]), &asset),
+                    &balance,
+                )
+                .unwrap();
+        }
+        let view = database.latest_view().unwrap();
+
+        let top = view.top_contract_holders(asset, 3).unwrap();
+
+        assert_eq!(
+            top,
+            vec![
+                (ContractId::from([2; 32]), 50),
+                (ContractId::from([4; 32]), 50),
+                (ContractId::from([3; 32]), 30),
+            ]
+        );
+        assert!(view.top_contract_holders(asset, 0).unwrap().is_empty());
+        assert_eq!(view.top_contract_holders(asset, 10).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn contract_state_root__matches_the_root_of_the_state() {
+        use fuel_core_types::fuel_merkle::sparse::{
+            in_memory::MerkleTree,
+            MerkleTreeKey,
+        };
+
+        let mut database = Database::<OnChain>::default();
+        let contract_id = ContractId::from([1; 32]);
+        let slots = [
+            (Bytes32::from([2; 32]), vec![3; 32]),
+            (Bytes32::from([4; 32]), vec![5; 32]),
+            (Bytes32::from([6; 32]), vec![7; 32]),
+        ];
+        for (slot, value) in &slots {
+            database
+                .storage_as_mut::<ContractsState>()
+                .insert(&ContractsStateKey::new(&contract_id, slot), value)
+                .unwrap();
+        }
+        let view = database.latest_view().unwrap();
+
+        let root = view.contract_state_root(&contract_id).unwrap();
+
+        let expected = MerkleTree::root_from_set(slots.iter().map(|(slot, value)| {
+            (
+                MerkleTreeKey::new(ContractsStateKey::new(&contract_id, slot)),
+                value,
+            )
+        }));
+        assert_eq!(root, Some(Bytes32::from(expected)));
+    }
+
+    #[test]
+    fn contract_state_root__is_none_without_state() {
+        let database = database_with_deployed_contracts(&[(1, vec![1; 4])]);
+        let view = database.latest_view().unwrap();
+
+        let root = view
+            .contract_state_root(&ContractId::from([1; 32]))
+            .unwrap();
+
+        assert_eq!(root, None);
+    }
+
+    #[test]
+    fn contract_origin__returns_the_tx_pointer_of_the_latest_utxo() {
+        let mut database = Database::<OnChain>::default();
+        let contract_id = ContractId::from([1; 32]);
+        let utxo_id = UtxoId::new([2; 32].into(), 0);
+        let tx_pointer = TxPointer::new(BlockHeight::from(42), 7);
+        database
+            .storage_as_mut::<ContractsLatestUtxo>()
+            .insert(
+                &contract_id,
+                &ContractUtxoInfo::V1((utxo_id, tx_pointer).into()),
+            )
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let origin = view.contract_origin(&contract_id).unwrap();
+
+        assert_eq!(origin, Some((BlockHeight::from(42), 7)));
+        assert_eq!(
+            view.contract_origin(&ContractId::from([3; 32])).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn contracts_deployed_in_range__returns_only_contracts_within_the_range() {
+        let mut database = Database::<OnChain>::default();
+        for (id, height) in [(1u8, 5u32), (2, 10), (3, 15), (4, 20), (5, 25)] {
+            let utxo_id = UtxoId::new([id; 32].into(), 0);
+            let tx_pointer = TxPointer::new(BlockHeight::from(height), 0);
+            database
+                .storage_as_mut::<ContractsLatestUtxo>()
+                .insert(
+                    &ContractId::from([id; 32]),
+                    &ContractUtxoInfo::V1((utxo_id, tx_pointer).into()),
+                )
+                .unwrap();
+        }
+        let view = database.latest_view().unwrap();
+
+        let deployed = view
+            .contracts_deployed_in_range(BlockHeight::from(10), BlockHeight::from(20))
+            .unwrap();
+
+        assert_eq!(
+            deployed,
+            vec![
+                ContractId::from([2; 32]),
+                ContractId::from([3; 32]),
+                ContractId::from([4; 32]),
+            ]
+        );
+        assert!(view
+            .contracts_deployed_in_range(BlockHeight::from(21), BlockHeight::from(24))
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -30,15 +30,30 @@ use fuel_core_storage::{
     StorageInspect,
     StorageMutate,
 };
-use fuel_core_types::fuel_merkle::binary::root_calculator::MerkleRootCalculator;
+use fuel_core_types::{
+    fuel_merkle::binary::root_calculator::MerkleRootCalculator,
+    fuel_types::Bytes32,
+};
 
 pub struct GenesisMetadata<Description>(core::marker::PhantomData<Description>);
 
+/// The checkpoint of a table import: the last processed group of the snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GenesisProgress {
+    /// The hash of the snapshot the group belongs to.
+    pub snapshot_hash: Bytes32,
+    /// The index of the last processed group.
+    pub processed_group: usize,
+    /// The number of entries of `processed_group` applied so far, or `None` if the
+    /// whole group was applied.
+    pub applied_entries: Option<usize>,
+}
+
 impl<Description> Mappable for GenesisMetadata<Description> {
     type Key = str;
     type OwnedKey = String;
     type Value = Self::OwnedValue;
-    type OwnedValue = usize;
+    type OwnedValue = GenesisProgress;
 }
 
 impl TableWithBlueprint for GenesisMetadata<OnChain> {
@@ -58,13 +73,14 @@ impl TableWithBlueprint for GenesisMetadata<OffChain> {
 }
 
 pub trait GenesisProgressInspect<Description> {
-    fn genesis_progress(&self, key: &str) -> Option<usize>;
+    fn genesis_progress(&self, key: &str) -> Option<GenesisProgress>;
 }
 
 pub trait GenesisProgressMutate<Description> {
     fn update_genesis_progress(
         &mut self,
         key: &str,
+        snapshot_hash: Bytes32,
         processed_group: usize,
     ) -> Result<()>;
 }
@@ -77,7 +93,7 @@ where
     fn genesis_progress(
         &self,
         key: &<GenesisMetadata<DbDesc> as Mappable>::Key,
-    ) -> Option<usize> {
+    ) -> Option<GenesisProgress> {
         Some(
             StorageInspect::<GenesisMetadata<DbDesc>>::get(self, key)
                 .ok()??
@@ -93,10 +109,16 @@ where
     fn update_genesis_progress(
         &mut self,
         key: &<GenesisMetadata<DbDesc> as Mappable>::Key,
+        snapshot_hash: Bytes32,
         processed_group: usize,
     ) -> Result<()> {
+        let progress = GenesisProgress {
+            snapshot_hash,
+            processed_group,
+            applied_entries: None,
+        };
         self.storage_as_mut::<GenesisMetadata<DbDesc>>()
-            .insert(key, &processed_group)?;
+            .insert(key, &progress)?;
 
         Ok(())
     }
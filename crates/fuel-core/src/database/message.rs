@@ -47,6 +47,28 @@ impl OffChainIterableKeyValueView {
     pub fn message_is_spent(&self, id: &Nonce) -> StorageResult<bool> {
         fuel_core_storage::StorageAsRef::storage::<SpentMessages>(&self).contains_key(id)
     }
+
+    /// Iterates over the nonces of the `owner`'s messages, skipping the ones marked as
+    /// spent in `SpentMessages`.
+    pub fn owner_unspent_messages(
+        &self,
+        owner: &Address,
+        start: Option<Nonce>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<Nonce>> + '_ {
+        self.owned_message_ids(owner, start, Some(direction))
+            .filter_map(move |nonce| {
+                let nonce = match nonce {
+                    Ok(nonce) => nonce,
+                    Err(err) => return Some(Err(err)),
+                };
+                match self.message_is_spent(&nonce) {
+                    Ok(true) => None,
+                    Ok(false) => Some(Ok(nonce)),
+                    Err(err) => Some(Err(err)),
+                }
+            })
+    }
 }
 
 impl OnChainIterableKeyValueView {
@@ -70,3 +92,69 @@ impl OnChainIterableKeyValueView {
         fuel_core_storage::StorageAsRef::storage::<Messages>(&self).contains_key(id)
     }
 }
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{
+        database_description::off_chain::OffChain,
+        Database,
+    };
+    use fuel_core_storage::{
+        transactional::AtomicView,
+        StorageAsMut,
+    };
+
+    #[test]
+    fn owner_unspent_messages__skips_spent_messages() {
+        let owner = Address::from([1; 32]);
+        let nonces = [1u8, 2, 3, 4].map(|byte| Nonce::from([byte; 32]));
+        let mut database = Database::<OffChain>::default();
+        for nonce in &nonces {
+            database
+                .storage::<OwnedMessageIds>()
+                .insert(&OwnedMessageKey::new(&owner, nonce), &())
+                .unwrap();
+        }
+        for spent in [&nonces[1], &nonces[3]] {
+            database
+                .storage::<SpentMessages>()
+                .insert(spent, &())
+                .unwrap();
+        }
+        let view = database.latest_view().unwrap();
+
+        let forward: Vec<_> = view
+            .owner_unspent_messages(&owner, None, IterDirection::Forward)
+            .try_collect()
+            .unwrap();
+        let reverse: Vec<_> = view
+            .owner_unspent_messages(&owner, None, IterDirection::Reverse)
+            .try_collect()
+            .unwrap();
+
+        assert_eq!(forward, vec![nonces[0], nonces[2]]);
+        assert_eq!(reverse, vec![nonces[2], nonces[0]]);
+    }
+
+    #[test]
+    fn owner_unspent_messages__ignores_messages_of_other_owners() {
+        let owner = Address::from([1; 32]);
+        let other_owner = Address::from([2; 32]);
+        let nonce = Nonce::from([3; 32]);
+        let mut database = Database::<OffChain>::default();
+        database
+            .storage::<OwnedMessageIds>()
+            .insert(&OwnedMessageKey::new(&other_owner, &nonce), &())
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        let result: Vec<_> = view
+            .owner_unspent_messages(&owner, None, IterDirection::Forward)
+            .try_collect()
+            .unwrap();
+
+        assert!(result.is_empty());
+    }
+}
@@ -25,6 +25,8 @@ pub struct ServiceConfig {
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
     pub api_request_timeout: Duration,
+    /// The max number of entries the `blocks` query returns in a page.
+    pub max_page_size: usize,
 }
 
 pub struct Costs {
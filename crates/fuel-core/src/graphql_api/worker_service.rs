@@ -54,6 +54,7 @@ use fuel_core_types::{
             CoinSigned,
         },
         Contract,
+        Create,
         Input,
         Output,
         Transaction,
@@ -63,6 +64,7 @@ use fuel_core_types::{
         BlockHeight,
         Bytes32,
         ChainId,
+        ContractId,
     },
     services::{
         block_importer::{
@@ -332,29 +334,7 @@ where
     for tx in transactions {
         match tx {
             Transaction::Create(tx) => {
-                let contract_id = tx
-                    .outputs()
-                    .iter()
-                    .filter_map(|output| output.contract_id().cloned())
-                    .next()
-                    .map(Ok::<_, StorageError>)
-                    .unwrap_or_else(|| {
-                        // TODO: Reuse `CreateMetadata` when it will be exported
-                        //  from the `fuel-tx` crate.
-                        let salt = tx.salt();
-                        let storage_slots = tx.storage_slots();
-                        let contract = Contract::try_from(tx)
-                            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
-                        let contract_root = contract.root();
-                        let state_root =
-                            Contract::initial_state_root(storage_slots.iter());
-                        Ok::<_, StorageError>(contract.id(
-                            salt,
-                            &contract_root,
-                            &state_root,
-                        ))
-                    })?;
-
+                let contract_id = created_contract_id(tx)?;
                 let salt = *tx.salt();
 
                 db.storage::<ContractsInfo>()
@@ -371,6 +351,26 @@ where
     Ok(())
 }
 
+/// The id of the contract deployed by the `Create` transaction.
+pub fn created_contract_id(tx: &Create) -> StorageResult<ContractId> {
+    tx.outputs()
+        .iter()
+        .filter_map(|output| output.contract_id().cloned())
+        .next()
+        .map(Ok::<_, StorageError>)
+        .unwrap_or_else(|| {
+            // TODO: Reuse `CreateMetadata` when it will be exported
+            //  from the `fuel-tx` crate.
+            let salt = tx.salt();
+            let storage_slots = tx.storage_slots();
+            let contract =
+                Contract::try_from(tx).map_err(|e| anyhow::anyhow!("{:?}", e))?;
+            let contract_root = contract.root();
+            let state_root = Contract::initial_state_root(storage_slots.iter());
+            Ok::<_, StorageError>(contract.id(salt, &contract_root, &state_root))
+        })
+}
+
 pub fn copy_to_old_blocks<'a, I, T>(blocks: I, db: &mut T) -> StorageResult<()>
 where
     I: Iterator<Item = (&'a BlockHeight, &'a CompressedBlock)>,
@@ -288,6 +288,14 @@ impl BlockQuery {
         last: Option<i32>,
         before: Option<String>,
     ) -> async_graphql::Result<Connection<U32, Block, EmptyFields, EmptyFields>> {
+        let max_page_size = ctx.data_unchecked::<GraphQLConfig>().config.max_page_size;
+        let page_size = first.or(last).unwrap_or_default();
+        if usize::try_from(page_size).is_ok_and(|page_size| page_size > max_page_size) {
+            return Err(anyhow!(
+                "The page size `{page_size}` exceeds the max page size `{max_page_size}`"
+            )
+            .into())
+        }
         let query = ctx.read_view()?;
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             Ok(blocks_query(
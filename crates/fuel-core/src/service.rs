@@ -10,8 +10,22 @@ use crate::{
         sub_services::TxPoolSharedState,
     },
 };
+use fuel_core_gas_price_service::{
+    fuel_gas_price_updater::{
+        get_block_info,
+        BlockCapacity,
+        Error as GasPriceError,
+        GasPriceResult,
+    },
+    static_updater::StaticAlgorithm,
+    SharedGasPriceAlgo,
+};
 use fuel_core_poa::ports::BlockImporter;
 use fuel_core_services::{
+    stream::{
+        BoxStream,
+        IntoBoxStream,
+    },
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -20,18 +34,39 @@ use fuel_core_services::{
 };
 use fuel_core_storage::{
     transactional::AtomicView,
+    Error as StorageError,
     IsNotFound,
 };
+use fuel_core_types::{
+    fuel_tx::{
+        field::MintGasPrice,
+        Transaction,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::{
+            ImportResult,
+            Source,
+        },
+        executor::TransactionExecutionResult,
+    },
+};
+use futures::StreamExt;
 use std::net::SocketAddr;
 
 pub use config::{
     Config,
     DbType,
     RelayerConsensusConfig,
+    Trigger,
     VMConfig,
 };
 pub use fuel_core_services::Service as ServiceTrait;
 
+/// The number of the latest blocks whose average fullness
+/// [`FuelService::estimate_future_gas_price`] projects the gas price with.
+pub const RECENT_FULLNESS_BLOCKS: u32 = 10;
+
 pub mod adapters;
 pub mod config;
 pub mod genesis;
@@ -64,10 +99,54 @@ pub struct SharedState {
     pub block_importer: BlockImporterAdapter,
     /// The executor to validate blocks.
     pub executor: ExecutorAdapter,
+    /// The gas price algorithm used for the next block.
+    pub gas_price_algo: SharedGasPriceAlgo<StaticAlgorithm>,
+    /// The progress of the snapshot import performed at genesis.
+    pub import_status: genesis::ImportStatusTracker,
     /// The config of the service.
     pub config: Config,
 }
 
+/// Describes a block produced and sealed by this node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockProducedEvent {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The number of transactions in the block, including the `Mint` transaction.
+    pub tx_count: usize,
+    /// The gas used by the transactions of the block.
+    pub gas_used: u64,
+}
+
+/// Describes the block production of a running node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockProductionInfo {
+    /// The trigger of the PoA block production, with its parameters.
+    pub trigger: Trigger,
+    /// Whether the node produces blocks. It is also the case for the `Never` trigger
+    /// when the node runs in `debug` mode, where blocks are produced manually.
+    pub enabled: bool,
+}
+
+impl From<&ImportResult> for BlockProducedEvent {
+    fn from(result: &ImportResult) -> Self {
+        let block = &result.sealed_block.entity;
+        let gas_used = result
+            .tx_status
+            .iter()
+            .map(|status| match &status.result {
+                TransactionExecutionResult::Success { total_gas, .. }
+                | TransactionExecutionResult::Failed { total_gas, .. } => *total_gas,
+            })
+            .fold(0u64, |gas_used, gas| gas_used.saturating_add(gas));
+        Self {
+            height: *block.header().height(),
+            tx_count: block.transactions().len(),
+            gas_used,
+        }
+    }
+}
+
 pub struct FuelService {
     /// The `ServiceRunner` used for `FuelService`.
     ///
@@ -139,6 +218,110 @@ impl FuelService {
         Ok(service)
     }
 
+    /// Returns the latest gas price reported by the gas price algorithm.
+    pub async fn current_gas_price(&self) -> u64 {
+        self.shared.gas_price_algo.last_gas_price().await
+    }
+
+    /// Returns the estimated gas price of the block `blocks_ahead` blocks after the latest
+    /// one. The gas price algorithm projects its price over `blocks_ahead` blocks, each
+    /// as full as the average of the last [`RECENT_FULLNESS_BLOCKS`] blocks.
+    ///
+    /// If the fullness of the recent blocks can't be read, the blocks are assumed empty.
+    pub async fn estimate_future_gas_price(&self, blocks_ahead: u32) -> u64 {
+        let fullness = self.recent_average_fullness().unwrap_or_else(|err| {
+            tracing::warn!("Failed to read the fullness of the recent blocks: {err}");
+            (0, 0)
+        });
+        self.shared
+            .gas_price_algo
+            .projected_gas_price(blocks_ahead, fullness)
+            .await
+    }
+
+    /// Returns the average `(used, capacity)` fullness of the last
+    /// [`RECENT_FULLNESS_BLOCKS`] blocks. Blocks without a coinbase `Mint`, like the
+    /// genesis block, are left out.
+    fn recent_average_fullness(&self) -> anyhow::Result<(u64, u64)> {
+        let database = self.shared.database.on_chain();
+        let Some(latest_height) = database.latest_height()? else {
+            return Ok((0, 0))
+        };
+        let view = database.latest_view()?;
+        let latest_height = u32::from(latest_height);
+        let first_height =
+            latest_height.saturating_sub(RECENT_FULLNESS_BLOCKS.saturating_sub(1));
+        let (mut used, mut capacity, mut blocks) = (0u128, 0u128, 0u128);
+        for height in first_height..=latest_height {
+            let Some(block) = view.get_full_block(&height.into())? else {
+                continue
+            };
+            if !matches!(block.transactions().last(), Some(Transaction::Mint(_))) {
+                continue
+            }
+            let block_capacity = BlockCapacity::for_block(&block, database)?;
+            let info = get_block_info(&block, &block_capacity)?;
+            used = used.saturating_add(u128::from(info.fullness.0));
+            capacity = capacity.saturating_add(u128::from(info.fullness.1));
+            blocks = blocks.saturating_add(1);
+        }
+        let average = |sum: u128| {
+            u64::try_from(sum.checked_div(blocks).unwrap_or_default()).unwrap_or(u64::MAX)
+        };
+        Ok((average(used), average(capacity)))
+    }
+
+    /// Returns the gas price the block at `height` was produced with, as recorded by its
+    /// coinbase `Mint` transaction, or `None` if the block is unknown. Blocks without a
+    /// `Mint`, like the genesis block, are reported with a zero gas price, the same as
+    /// the `latestGasPrice` GraphQL query does.
+    pub fn gas_price_at_block(&self, height: BlockHeight) -> GasPriceResult<Option<u64>> {
+        let could_not_fetch = |err: StorageError| GasPriceError::CouldNotFetchL2Block {
+            block_height: height,
+            source_error: err.into(),
+        };
+        let view = self
+            .shared
+            .database
+            .on_chain()
+            .latest_view()
+            .map_err(could_not_fetch)?;
+        let Some(block) = view.get_full_block(&height).map_err(could_not_fetch)? else {
+            return Ok(None)
+        };
+        let gas_price = match block.transactions().last() {
+            Some(Transaction::Mint(mint)) => *mint.gas_price(),
+            _ => 0,
+        };
+        Ok(Some(gas_price))
+    }
+
+    /// Subscribes to the blocks produced by this node, skipping the ones received from
+    /// the network. Only blocks committed after the subscription are reported.
+    pub fn subscribe_block_production(&self) -> BoxStream<BlockProducedEvent> {
+        self.shared
+            .block_importer
+            .events()
+            .filter(|result| futures::future::ready(result.source == Source::Local))
+            .map(|result| BlockProducedEvent::from(&**result))
+            .into_boxed()
+    }
+
+    /// Returns the block production trigger the node is running with.
+    pub fn block_production_config(&self) -> BlockProductionInfo {
+        BlockProductionInfo {
+            trigger: self.shared.config.block_production,
+            enabled: self.shared.poa_adapter.is_enabled(),
+        }
+    }
+
+    /// Returns the progress of the snapshot import performed at genesis.
+    ///
+    /// Orchestrators can poll it while the node is starting to follow a long import.
+    pub fn import_status(&self) -> genesis::ImportStatus {
+        self.shared.import_status.status()
+    }
+
     #[cfg(feature = "relayer")]
     /// Wait for the Relayer to be in sync with
     /// the data availability layer.
@@ -245,6 +428,7 @@ impl RunnableService for Task {
                     watcher.clone(),
                     &self.shared.config,
                     &self.shared.database,
+                    &self.shared.import_status,
                 )
                 .await?;
 
@@ -23,7 +23,10 @@ use fuel_core_types::{
     services::block_importer::SharedImportResult,
 };
 use fuel_core_upgradable_executor::executor::Executor;
-use std::sync::Arc;
+use std::sync::{
+    atomic::AtomicU64,
+    Arc,
+};
 
 pub mod block_importer;
 pub mod consensus_module;
@@ -71,11 +74,21 @@ pub struct PoAAdapter {
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState,
+    block_gas_limit: Option<u64>,
 }
 
 impl TxPoolAdapter {
     pub fn new(service: TxPoolSharedState) -> Self {
-        Self { service }
+        Self {
+            service,
+            block_gas_limit: None,
+        }
+    }
+
+    /// Caps the total gas of transactions selected for a single block.
+    pub fn with_block_gas_limit(mut self, block_gas_limit: Option<u64>) -> Self {
+        self.block_gas_limit = block_gas_limit;
+        self
     }
 }
 
@@ -83,6 +96,8 @@ impl TxPoolAdapter {
 pub struct TransactionsSource {
     txpool: TxPoolSharedState,
     _block_height: BlockHeight,
+    /// The gas still available to the block, if the block gas limit is overridden.
+    remaining_gas: Option<Arc<AtomicU64>>,
 }
 
 impl TransactionsSource {
@@ -90,8 +105,14 @@ impl TransactionsSource {
         Self {
             txpool,
             _block_height: block_height,
+            remaining_gas: None,
         }
     }
+
+    pub fn with_block_gas_limit(mut self, block_gas_limit: Option<u64>) -> Self {
+        self.remaining_gas = block_gas_limit.map(|limit| Arc::new(AtomicU64::new(limit)));
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -49,6 +49,11 @@ impl PoAAdapter {
         Self { shared_state }
     }
 
+    /// Whether the PoA service is running, i.e. the node produces blocks.
+    pub fn is_enabled(&self) -> bool {
+        self.shared_state.is_some()
+    }
+
     pub async fn manually_produce_blocks(
         &self,
         start_time: Option<Tai64>,
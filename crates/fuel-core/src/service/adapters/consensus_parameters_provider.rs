@@ -2,6 +2,7 @@ use crate::{
     database::Database,
     service::adapters::BlockImporterAdapter,
 };
+use fuel_core_gas_price_service::fuel_gas_price_updater::ConsensusParamsProvider;
 use fuel_core_producer::ports::BlockProducerDatabase;
 use fuel_core_services::{
     stream::BoxStream,
@@ -108,6 +109,23 @@ impl SharedState {
     }
 }
 
+impl ConsensusParamsProvider for Database {
+    fn get_params(
+        &self,
+        version: ConsensusParametersVersion,
+    ) -> Option<ConsensusParameters> {
+        match self.storage::<ConsensusParametersVersions>().get(&version) {
+            Ok(params) => params.map(|params| params.into_owned()),
+            Err(err) => {
+                tracing::error!(
+                    "Failed to read the consensus parameters of the version {version}: {err}"
+                );
+                None
+            }
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl RunnableTask for Task {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
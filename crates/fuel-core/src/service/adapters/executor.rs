@@ -7,11 +7,24 @@ use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     services::relayer::Event,
 };
+use std::sync::atomic::Ordering;
 
 impl fuel_core_executor::ports::TransactionsSource for TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
-        self.txpool
-            .select_transactions(gas_limit)
+        let gas_limit = match &self.remaining_gas {
+            Some(remaining_gas) => gas_limit.min(remaining_gas.load(Ordering::Acquire)),
+            None => gas_limit,
+        };
+        let transactions = self.txpool.select_transactions(gas_limit);
+        if let Some(remaining_gas) = &self.remaining_gas {
+            let selected_gas = transactions
+                .iter()
+                .fold(0u64, |sum, tx| sum.saturating_add(tx.max_gas()));
+            remaining_gas
+                .store(gas_limit.saturating_sub(selected_gas), Ordering::Release);
+        }
+
+        transactions
             .into_iter()
             .map(|tx| {
                 MaybeCheckedTransaction::CheckedTransaction(
@@ -38,6 +38,11 @@ impl GasPriceAlgorithm for TestGasPriceAlgorithm {
     fn worst_case_gas_price(&self, _block_height: BlockHeight) -> u64 {
         self.multiply.saturating_mul(10_000_000) // Arbitrary fake bytes
     }
+
+    fn projected_gas_price(&self, blocks_ahead: u32, fullness: (u64, u64)) -> u64 {
+        self.last
+            .saturating_add(u64::from(blocks_ahead).saturating_mul(fullness.0))
+    }
 }
 
 fn build_provider<A>(algorithm: A) -> FuelGasPriceProvider<A>
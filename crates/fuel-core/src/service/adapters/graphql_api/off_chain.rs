@@ -113,13 +113,8 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
     }
 
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
-        let salt = *self
-            .storage_as_ref::<ContractsInfo>()
-            .get(contract_id)?
-            .ok_or(not_found!(ContractsInfo))?
-            .salt();
-
-        Ok(salt)
+        OffChainIterableKeyValueView::contract_salt(self, contract_id)?
+            .ok_or(not_found!(ContractsInfo))
     }
 
     fn old_block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock> {
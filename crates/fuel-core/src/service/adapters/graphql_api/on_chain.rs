@@ -47,6 +47,7 @@ use fuel_core_types::{
     services::graphql_api::ContractBalance,
 };
 use itertools::Itertools;
+use std::ops::Bound;
 
 impl DatabaseBlocks for OnChainIterableKeyValueView {
     fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
@@ -112,6 +113,7 @@ impl DatabaseContracts for OnChainIterableKeyValueView {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>> {
+        let start_asset = start_asset.map_or(Bound::Unbounded, Bound::Included);
         self.filter_contract_balances(contract, start_asset, Some(direction))
             .map_ok(|entry| ContractBalance {
                 owner: *entry.key.contract_id(),
@@ -82,6 +82,7 @@ impl TxPool for TxPoolAdapter {
 
     fn get_source(&self, block_height: BlockHeight) -> Self::TxSource {
         TransactionsSource::new(self.service.clone(), block_height)
+            .with_block_gas_limit(self.block_gas_limit)
     }
 }
 
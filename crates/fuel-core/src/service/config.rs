@@ -36,6 +36,7 @@ pub use fuel_core_poa::Trigger;
 use crate::{
     combined_database::CombinedDatabaseConfig,
     graphql_api::ServiceConfig as GraphQLConfig,
+    service::genesis::GenesisImportConfig,
 };
 
 #[derive(Clone, Debug)]
@@ -70,8 +71,21 @@ pub struct Config {
     pub min_connected_reserved_peers: usize,
     /// Time to wait after receiving the latest block before considered to be Synced.
     pub time_until_synced: Duration,
+    /// Skips producing a block at an interval tick if the transaction pool is empty.
+    pub skip_empty_blocks: bool,
     /// The size of the memory pool in number of `MemoryInstance`s.
     pub memory_pool_size: usize,
+    /// Time allowed for importing the snapshot at genesis. Unlimited if `None`.
+    pub genesis_import_timeout: Option<Duration>,
+    /// Cross-checks the imported `Messages` and `SpentMessages` tables after the import.
+    pub genesis_validate_messages: bool,
+    /// Cross-checks the imported historical blocks and their consensus after the import.
+    pub genesis_validate_consensus: bool,
+    /// The limits of the snapshot import at genesis.
+    pub genesis_import: GenesisImportConfig,
+    /// Caps the gas of transactions included into each produced block, regardless of
+    /// the consensus parameters. Intended for tests that need small blocks.
+    pub test_block_gas_limit: Option<u64>,
 }
 
 impl Config {
@@ -118,6 +132,7 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            metrics: false,
         };
 
         Self {
@@ -132,6 +147,7 @@ impl Config {
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
                 api_request_timeout: Duration::from_secs(60),
+                max_page_size: 10_000,
             },
             combined_db_config,
             debug: true,
@@ -163,7 +179,13 @@ impl Config {
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            skip_empty_blocks: false,
             memory_pool_size: 4,
+            genesis_import_timeout: None,
+            genesis_validate_messages: false,
+            genesis_validate_consensus: false,
+            genesis_import: GenesisImportConfig::default(),
+            test_block_gas_limit: None,
         }
     }
 
@@ -193,6 +215,7 @@ impl From<&Config> for fuel_core_poa::Config {
             metrics: false,
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            skip_empty_blocks: config.skip_empty_blocks,
         }
     }
 }
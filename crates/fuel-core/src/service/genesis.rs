@@ -59,27 +59,63 @@ mod progress;
 mod task_manager;
 
 pub use exporter::Exporter;
+pub use importer::{
+    ConflictPolicy,
+    EntryMapFn,
+    EntryMaps,
+    GenesisImportConfig,
+    ImportSummary,
+    SnapshotImporter,
+    GENESIS_IMPORT_LOCK_FILE,
+};
+pub use progress::{
+    ImportStatus,
+    ImportStatusTracker,
+};
 pub use task_manager::NotifyCancel;
 
-use self::importer::SnapshotImporter;
+use self::importer::{
+    validate_block_consensus,
+    validate_message_state,
+};
 
 /// Performs the importing of the genesis block from the snapshot.
 pub async fn execute_genesis_block(
     watcher: StateWatcher,
     config: &Config,
     db: &CombinedDatabase,
+    import_status: &ImportStatusTracker,
 ) -> anyhow::Result<UncommittedImportResult<Changes>> {
     let genesis_block = create_genesis_block(config);
     tracing::info!("Genesis block created: {:?}", genesis_block.header());
     let db = db.clone().into_genesis();
 
-    SnapshotImporter::import(
+    let summary = SnapshotImporter::import(
         db.clone(),
         genesis_block.clone(),
         config.snapshot_reader.clone(),
         watcher,
+        ConflictPolicy::default(),
+        import_status.clone(),
+        config.genesis_import_timeout,
+        config.genesis_import.clone(),
+        true,
+        None,
+        EntryMaps::default(),
     )
     .await?;
+    if config.genesis_validate_messages {
+        validate_message_state(&db)?;
+    }
+    if config.genesis_validate_consensus {
+        validate_block_consensus(&db)?;
+    }
+    tracing::info!(
+        "Imported {} rows from the snapshot in {:?}: {:?}",
+        summary.total_rows,
+        summary.elapsed,
+        summary.per_table
+    );
 
     let genesis_progress_on_chain: Vec<String> = db
         .on_chain()
@@ -157,7 +193,13 @@ pub async fn execute_and_commit_genesis_block(
     config: &Config,
     db: &CombinedDatabase,
 ) -> anyhow::Result<()> {
-    let result = execute_genesis_block(StateWatcher::default(), config, db).await?;
+    let result = execute_genesis_block(
+        StateWatcher::default(),
+        config,
+        db,
+        &ImportStatusTracker::default(),
+    )
+    .await?;
     let importer = fuel_core_importer::Importer::new(
         config
             .snapshot_reader
@@ -275,6 +317,7 @@ mod tests {
             Coins,
             ContractsAssets,
             ContractsState,
+            FuelBlocks,
         },
         transactional::AtomicView,
         StorageAsRef,
@@ -325,6 +368,25 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn empty_snapshot_produces_the_genesis_block() {
+        let service_config = Config::local_node_with_state_config(StateConfig::default());
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let genesis_height = db
+            .latest_height()
+            .unwrap()
+            .expect("Expected a block height to be set");
+        assert!(db
+            .storage::<FuelBlocks>()
+            .contains_key(&genesis_height)
+            .unwrap());
+    }
+
     #[tokio::test]
     async fn genesis_columns_are_cleared_after_import() {
         let mut rng = StdRng::seed_from_u64(10);
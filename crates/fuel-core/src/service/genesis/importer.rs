@@ -1,9 +1,18 @@
 use super::{
-    progress::MultipleProgressReporter,
-    task_manager::TaskManager,
+    progress::{
+        ImportStatusTracker,
+        MultipleProgressReporter,
+    },
+    task_manager::{
+        CancellationToken,
+        TaskManager,
+    },
 };
 use crate::{
-    combined_database::CombinedGenesisDatabase,
+    combined_database::{
+        CombinedDatabase,
+        CombinedGenesisDatabase,
+    },
     database::database_description::{
         off_chain::OffChain,
         on_chain::OnChain,
@@ -25,15 +34,22 @@ use crate::{
         },
     },
 };
-use core::marker::PhantomData;
+use core::{
+    any::Any,
+    borrow::Borrow,
+    marker::PhantomData,
+};
 use fuel_core_chain_config::{
     AsTable,
+    Groups,
+    SnapshotError,
     SnapshotReader,
     StateConfig,
     TableEntry,
 };
 use fuel_core_services::StateWatcher;
 use fuel_core_storage::{
+    iter::IteratorOverTable,
     kv_store::StorageColumn,
     structured_storage::TableWithBlueprint,
     tables::{
@@ -52,64 +68,396 @@ use fuel_core_storage::{
         SealedBlockConsensus,
         Transactions,
     },
+    StorageAsRef,
+    StorageInspect,
 };
 use fuel_core_types::{
     blockchain::{
         block::Block,
         primitives::DaBlockHeight,
     },
-    fuel_types::BlockHeight,
+    fuel_tx::TxId,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
 };
+pub use import_task::EntryMapFn;
 use import_task::{
     ImportTable,
     ImportTask,
 };
+use prefetch::Prefetch;
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+        HashSet,
+    },
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 mod import_task;
 mod off_chain;
 mod on_chain;
+mod prefetch;
 
 const GROUPS_NUMBER_FOR_PARALLELIZATION: usize = 10;
+/// The number of groups read ahead of the one being applied.
+const PREFETCHED_GROUPS: usize = 2;
+
+/// Tunes the resources used by the [`SnapshotImporter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisImportConfig {
+    /// The maximum number of tables imported in parallel. Unlimited if `None`.
+    pub max_concurrent_tables: Option<usize>,
+    /// The maximum number of snapshot entries applied per write batch.
+    /// Each group of the snapshot is applied as a single batch if `None`.
+    pub apply_batch_size: Option<usize>,
+    /// The number of groups of a table read ahead of the one being applied.
+    pub prefetch_depth: usize,
+    /// The file held for the duration of the import, so that a second import into the
+    /// same database fails fast. No lock is taken if `None`.
+    pub lock_file: Option<PathBuf>,
+}
+
+impl Default for GenesisImportConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_tables: None,
+            apply_batch_size: None,
+            prefetch_depth: PREFETCHED_GROUPS,
+            lock_file: None,
+        }
+    }
+}
+
+/// The name of the lock file taken in the database directory during the import.
+pub const GENESIS_IMPORT_LOCK_FILE: &str = "genesis_import.lock";
+
+/// An exclusive lock on the import of a database, released on drop.
+///
+/// The lock is an advisory lock of the OS on the file, so the OS releases it when the
+/// holding process exits, even if it crashed. The file itself is left in place, and a
+/// file left behind by a previous import doesn't block a new one.
+#[derive(Debug)]
+struct ImportLock {
+    _file: std::fs::File,
+}
+
+impl ImportLock {
+    fn acquire(path: PathBuf) -> anyhow::Result<Self> {
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(|err| {
+                anyhow::Error::new(err).context(format!(
+                    "Failed to open the import lock file {}",
+                    path.display()
+                ))
+            })?;
+        match fs2::FileExt::try_lock_exclusive(&file) {
+            Ok(()) => {}
+            Err(err) if err.kind() == fs2::lock_contended_error().kind() => {
+                return Err(
+                    SnapshotError::ImportAlreadyInProgress { lock_file: path }.into()
+                )
+            }
+            Err(err) => {
+                return Err(anyhow::Error::new(err).context(format!(
+                    "Failed to lock the import lock file {}",
+                    path.display()
+                )))
+            }
+        }
+        // The id of the holder helps to find out which process runs the import.
+        file.set_len(0)?;
+        std::io::Write::write_all(&mut file, std::process::id().to_string().as_bytes())?;
+        Ok(Self { _file: file })
+    }
+}
 
 pub struct SnapshotImporter {
     db: CombinedGenesisDatabase,
-    task_manager: TaskManager<()>,
+    task_manager: TaskManager<(String, u64)>,
     genesis_block: Block,
     snapshot_reader: SnapshotReader,
+    snapshot_hash: Bytes32,
     multi_progress_reporter: MultipleProgressReporter,
+    on_conflict: ConflictPolicy,
+    summary: ImportSummary,
+    status: ImportStatusTracker,
+    timeout: Option<Duration>,
+    import_config: GenesisImportConfig,
+    include_off_chain: bool,
+    old_blocks_floor: Option<BlockHeight>,
+    dropped_old_transactions: Arc<HashSet<TxId>>,
+    unfinished_tables: Arc<Mutex<BTreeSet<String>>>,
+    entry_maps: EntryMaps,
+    _lock: Option<ImportLock>,
+}
+
+/// Defines how the import treats rows of the snapshot that already exist in the database.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Replace the existing row with the one from the snapshot.
+    Overwrite,
+    /// Keep the existing row and ignore the one from the snapshot.
+    Skip,
+    /// Fail the import.
+    #[default]
+    Error,
+}
+
+/// The amount of rows applied by a run of the [`SnapshotImporter`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Rows applied by each migration, keyed by the name of the migration.
+    pub per_table: BTreeMap<String, u64>,
+    /// Rows applied across all migrations.
+    pub total_rows: u64,
+    /// Time spent on the import.
+    pub elapsed: Duration,
+}
+
+impl ImportSummary {
+    fn record(&mut self, migration_name: String, rows: u64) {
+        let table_rows = self.per_table.entry(migration_name).or_default();
+        *table_rows = table_rows.saturating_add(rows);
+        self.total_rows = self.total_rows.saturating_add(rows);
+    }
+
+    fn merge(&mut self, other: ImportSummary) {
+        for (migration_name, rows) in other.per_table {
+            self.record(migration_name, rows);
+        }
+        self.elapsed = self.elapsed.saturating_add(other.elapsed);
+    }
+}
+
+/// The [`EntryMapFn`]s applied by the [`SnapshotImporter`] to the entries of the
+/// snapshot, at most one per migration.
+#[derive(Default)]
+pub struct EntryMaps(HashMap<String, Box<dyn Any + Send>>);
+
+impl EntryMaps {
+    /// Passes the entries of `TableInSnapshot` through `map_fn` before they are written
+    /// into `TableBeingWritten`. Other migrations reading `TableInSnapshot` see the
+    /// entries unchanged.
+    pub fn with<TableInSnapshot, TableBeingWritten>(
+        mut self,
+        map_fn: EntryMapFn<TableInSnapshot>,
+    ) -> Self
+    where
+        TableInSnapshot: TableWithBlueprint + 'static,
+        TableBeingWritten: TableWithBlueprint,
+    {
+        self.0.insert(
+            migration_name::<TableInSnapshot, TableBeingWritten>(),
+            Box::new(map_fn),
+        );
+        self
+    }
+
+    fn take<TableInSnapshot, TableBeingWritten>(
+        &mut self,
+    ) -> Option<EntryMapFn<TableInSnapshot>>
+    where
+        TableInSnapshot: TableWithBlueprint + 'static,
+        TableBeingWritten: TableWithBlueprint,
+    {
+        let map_fn = self
+            .0
+            .remove(&migration_name::<TableInSnapshot, TableBeingWritten>())?;
+        map_fn
+            .downcast::<EntryMapFn<TableInSnapshot>>()
+            .ok()
+            .map(|map_fn| *map_fn)
+    }
 }
 
 impl SnapshotImporter {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         db: CombinedGenesisDatabase,
         genesis_block: Block,
         snapshot_reader: SnapshotReader,
+        snapshot_hash: Bytes32,
         watcher: StateWatcher,
-    ) -> Self {
-        Self {
+        on_conflict: ConflictPolicy,
+        status: ImportStatusTracker,
+        timeout: Option<Duration>,
+        import_config: GenesisImportConfig,
+        include_off_chain: bool,
+        old_blocks_floor: Option<BlockHeight>,
+        entry_maps: EntryMaps,
+    ) -> anyhow::Result<Self> {
+        let lock = import_config
+            .lock_file
+            .clone()
+            .map(ImportLock::acquire)
+            .transpose()?;
+        Ok(Self {
             db,
             genesis_block,
-            task_manager: TaskManager::new(watcher),
+            task_manager: TaskManager::new(watcher)
+                .with_max_concurrency(import_config.max_concurrent_tables),
             snapshot_reader,
+            snapshot_hash,
             multi_progress_reporter: MultipleProgressReporter::new(tracing::info_span!(
                 "snapshot_importer"
             )),
-        }
+            on_conflict,
+            summary: ImportSummary::default(),
+            status,
+            timeout,
+            import_config,
+            include_off_chain,
+            old_blocks_floor,
+            dropped_old_transactions: Default::default(),
+            unfinished_tables: Default::default(),
+            entry_maps,
+            _lock: lock,
+        })
     }
 
+    /// Imports the snapshot into `db`.
+    ///
+    /// If the workers don't finish within `timeout`, they are cancelled and
+    /// [`SnapshotError::ImportTimeout`] is returned with the tables left unfinished.
+    /// The `import_config` limits the tables imported in parallel, the entries applied
+    /// per write batch and the groups read ahead of each table. If its `lock_file` is held by
+    /// another import, [`SnapshotError::ImportAlreadyInProgress`] is returned before any
+    /// row is applied. An empty snapshot is skipped without
+    /// spawning any workers. The off-chain tables are only imported if
+    /// `include_off_chain` is set. With an `old_blocks_floor`, the historical blocks and
+    /// their consensus below the floor are dropped, together with their transactions.
+    /// The `entry_maps` observe or transform the entries of their migrations before they
+    /// are written, and the rows they drop aren't counted in the summary.
+    #[allow(clippy::too_many_arguments)]
     pub async fn import(
         db: CombinedGenesisDatabase,
         genesis_block: Block,
         snapshot_reader: SnapshotReader,
         watcher: StateWatcher,
-    ) -> anyhow::Result<()> {
-        Self::new(db, genesis_block, snapshot_reader, watcher)
-            .run_workers()
-            .await
+        on_conflict: ConflictPolicy,
+        status: ImportStatusTracker,
+        timeout: Option<Duration>,
+        import_config: GenesisImportConfig,
+        include_off_chain: bool,
+        old_blocks_floor: Option<BlockHeight>,
+        entry_maps: EntryMaps,
+    ) -> anyhow::Result<ImportSummary> {
+        if snapshot_reader.is_empty() {
+            tracing::info!("The snapshot has no entries, skipping the import");
+            return Ok(ImportSummary::default());
+        }
+        let snapshot_hash = snapshot_reader.snapshot_hash()?;
+        status.start();
+        let importer = Self::new(
+            db,
+            genesis_block,
+            snapshot_reader,
+            snapshot_hash,
+            watcher,
+            on_conflict,
+            status.clone(),
+            timeout,
+            import_config,
+            include_off_chain,
+            old_blocks_floor,
+            entry_maps,
+        );
+        let result = match importer {
+            Ok(importer) => importer.run_workers().await,
+            Err(err) => Err(err),
+        };
+        status.finish();
+        result
+    }
+
+    /// Imports the `base` snapshot into `db` and overlays the `delta` snapshot, which
+    /// contains only the rows changed since the base. Rows of both snapshots replace the
+    /// existing ones, so the resulting state matches a full snapshot at the height of
+    /// the delta, and an interrupted import can be restarted from scratch.
+    pub async fn import_with_delta(
+        db: CombinedGenesisDatabase,
+        genesis_block: Block,
+        base_reader: SnapshotReader,
+        delta_reader: SnapshotReader,
+        watcher: StateWatcher,
+    ) -> anyhow::Result<ImportSummary> {
+        let mut summary = Self::import(
+            db.clone(),
+            genesis_block.clone(),
+            base_reader,
+            watcher.clone(),
+            ConflictPolicy::Overwrite,
+            ImportStatusTracker::default(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await?;
+        let delta_summary = Self::import(
+            db,
+            genesis_block,
+            delta_reader,
+            watcher,
+            ConflictPolicy::Overwrite,
+            ImportStatusTracker::default(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await?;
+        summary.merge(delta_summary);
+        Ok(summary)
+    }
+
+    /// Imports the snapshot into a new in-memory [`CombinedDatabase`] and returns it, so
+    /// the imported state can be inspected without setting up a node, e.g. when
+    /// validating a snapshot.
+    pub async fn import_to_memory(
+        genesis_block: Block,
+        snapshot_reader: SnapshotReader,
+    ) -> anyhow::Result<CombinedDatabase> {
+        let db = CombinedDatabase::in_memory();
+        Self::import(
+            db.clone().into_genesis(),
+            genesis_block,
+            snapshot_reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await?;
+        Ok(db)
     }
 
-    async fn run_workers(mut self) -> anyhow::Result<()> {
+    async fn run_workers(mut self) -> anyhow::Result<ImportSummary> {
         tracing::info!("Running imports");
+        let started_at = Instant::now();
         self.spawn_worker_on_chain::<Coins>()?;
         self.spawn_worker_on_chain::<Messages>()?;
         self.spawn_worker_on_chain::<ContractsRawCode>()?;
@@ -120,28 +468,44 @@ impl SnapshotImporter {
         self.spawn_worker_on_chain::<FuelBlockMerkleData>()?;
         self.spawn_worker_on_chain::<FuelBlockMerkleMetadata>()?;
 
-        self.spawn_worker_off_chain::<TransactionStatuses, TransactionStatuses>()?;
-        self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?;
-        self.spawn_worker_off_chain::<SpentMessages, SpentMessages>()?;
-        self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
-        self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
-        self.spawn_worker_off_chain::<FuelBlocks, OldFuelBlocks>()?;
-        self.spawn_worker_off_chain::<Transactions, OldTransactions>()?;
-        self.spawn_worker_off_chain::<SealedBlockConsensus, OldFuelBlockConsensus>()?;
-        self.spawn_worker_off_chain::<Transactions, ContractsInfo>()?;
-        self.spawn_worker_off_chain::<OldTransactions, ContractsInfo>()?;
-        self.spawn_worker_off_chain::<OldFuelBlocks, OldFuelBlocks>()?;
-        self.spawn_worker_off_chain::<OldFuelBlockConsensus, OldFuelBlockConsensus>()?;
-        self.spawn_worker_off_chain::<OldTransactions, OldTransactions>()?;
-        self.spawn_worker_off_chain::<FuelBlocks, FuelBlockIdsToHeights>()?;
-        self.spawn_worker_off_chain::<OldFuelBlocks, FuelBlockIdsToHeights>()?;
-
-        self.task_manager.wait().await?;
+        if self.include_off_chain {
+            self.dropped_old_transactions =
+                Arc::new(self.old_transactions_below_floor()?);
+            self.spawn_worker_off_chain::<TransactionStatuses, TransactionStatuses>()?;
+            self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?;
+            self.spawn_worker_off_chain::<SpentMessages, SpentMessages>()?;
+            self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
+            self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
+            self.spawn_worker_off_chain::<FuelBlocks, OldFuelBlocks>()?;
+            self.spawn_worker_off_chain::<Transactions, OldTransactions>()?;
+            self.spawn_worker_off_chain::<SealedBlockConsensus, OldFuelBlockConsensus>()?;
+            self.spawn_worker_off_chain::<Transactions, ContractsInfo>()?;
+            self.spawn_worker_off_chain::<OldTransactions, ContractsInfo>()?;
+            self.spawn_worker_off_chain::<OldFuelBlocks, OldFuelBlocks>()?;
+            self.spawn_worker_off_chain::<OldFuelBlockConsensus, OldFuelBlockConsensus>(
+            )?;
+            self.spawn_worker_off_chain::<OldTransactions, OldTransactions>()?;
+            self.spawn_worker_off_chain::<FuelBlocks, FuelBlockIdsToHeights>()?;
+            self.spawn_worker_off_chain::<OldFuelBlocks, FuelBlockIdsToHeights>()?;
+        } else {
+            tracing::info!("Skipping the off-chain tables");
+        }
 
-        Ok(())
+        let results =
+            wait_for_workers(self.task_manager, &self.unfinished_tables, self.timeout)
+                .await?;
+        let mut summary = self.summary;
+        for (migration_name, rows) in results {
+            summary.record(migration_name, rows);
+        }
+        summary.elapsed = started_at.elapsed();
+
+        Ok(summary)
     }
 
-    pub fn spawn_worker_on_chain<TableBeingWritten>(&mut self) -> anyhow::Result<()>
+    /// Spawns the import of the on-chain table, passing its entries through the
+    /// [`EntryMapFn`] of the migration, if any, before they are written.
+    fn spawn_worker_on_chain<TableBeingWritten>(&mut self) -> anyhow::Result<()>
     where
         TableBeingWritten: TableWithBlueprint + 'static + Send,
         TableEntry<TableBeingWritten>: serde::de::DeserializeOwned + Send,
@@ -149,43 +513,50 @@ impl SnapshotImporter {
         Handler<TableBeingWritten, TableBeingWritten>:
             ImportTable<TableInSnapshot = TableBeingWritten, DbDesc = OnChain>,
     {
-        let groups = self.snapshot_reader.read::<TableBeingWritten>()?;
+        let Some(groups) = self.read_table::<TableBeingWritten>()? else {
+            return Ok(());
+        };
         let num_groups = groups.len();
 
+        let migration_name = migration_name::<TableBeingWritten, TableBeingWritten>();
         // Even though genesis is expected to last orders of magnitude longer than an empty task
         // might take to execute, this optimization is placed regardless to speed up
         // unit/integration tests that will feel the impact more than actual regenesis.
         if num_groups == 0 {
+            tracing::info!(
+                "Skipping `{migration_name}`, the snapshot has no entries for it"
+            );
             return Ok(());
         }
 
         let block_height = *self.genesis_block.header().height();
         let da_block_height = self.genesis_block.header().da_height;
         let db = self.db.on_chain().clone();
+        let map_fn = self
+            .entry_maps
+            .take::<TableBeingWritten, TableBeingWritten>();
 
-        let migration_name = migration_name::<TableBeingWritten, TableBeingWritten>();
         let progress_reporter = self
             .multi_progress_reporter
-            .table_reporter(Some(num_groups), migration_name);
+            .table_reporter(Some(num_groups), migration_name.clone());
 
         let task = ImportTask::new(
-            Handler::new(block_height, da_block_height),
-            groups,
+            Handler::new(block_height, da_block_height, self.on_conflict),
+            Prefetch::new(groups, self.import_config.prefetch_depth),
             db,
             progress_reporter,
-        );
+            self.snapshot_hash,
+        )
+        .with_apply_batch_size(self.import_config.apply_batch_size)
+        .with_map_fn(map_fn);
 
-        let import = |token| task.run(token);
-        if num_groups < GROUPS_NUMBER_FOR_PARALLELIZATION {
-            self.task_manager.run(import)?;
-        } else {
-            self.task_manager.spawn_blocking(import);
-        }
-
-        Ok(())
+        self.schedule(migration_name, num_groups, move |token| task.run(token))
     }
 
-    pub fn spawn_worker_off_chain<TableInSnapshot, TableBeingWritten>(
+    /// Spawns the import of the off-chain table from the entries of `TableInSnapshot`,
+    /// passing them through the [`EntryMapFn`] of the migration, if any, before they are
+    /// written.
+    fn spawn_worker_off_chain<TableInSnapshot, TableBeingWritten>(
         &mut self,
     ) -> anyhow::Result<()>
     where
@@ -196,13 +567,19 @@ impl SnapshotImporter {
             ImportTable<TableInSnapshot = TableInSnapshot, DbDesc = OffChain>,
         TableBeingWritten: TableWithBlueprint + Send + 'static,
     {
-        let groups = self.snapshot_reader.read::<TableInSnapshot>()?;
+        let Some(groups) = self.read_table::<TableInSnapshot>()? else {
+            return Ok(());
+        };
         let num_groups = groups.len();
 
+        let migration_name = migration_name::<TableInSnapshot, TableBeingWritten>();
         // Even though genesis is expected to last orders of magnitude longer than an empty task
         // might take to execute, this optimization is placed regardless to speed up
         // unit/integration tests that will feel the impact more than actual regenesis.
         if num_groups == 0 {
+            tracing::info!(
+                "Skipping `{migration_name}`, the snapshot has no entries for it"
+            );
             return Ok(());
         }
 
@@ -210,21 +587,105 @@ impl SnapshotImporter {
         let da_block_height = self.genesis_block.header().da_height;
 
         let db = self.db.off_chain().clone();
+        let map_fn = self.entry_maps.take::<TableInSnapshot, TableBeingWritten>();
 
-        let migration_name = migration_name::<TableInSnapshot, TableBeingWritten>();
         let progress_reporter = self
             .multi_progress_reporter
-            .table_reporter(Some(num_groups), migration_name);
+            .table_reporter(Some(num_groups), migration_name.clone());
 
         let task = ImportTask::new(
-            Handler::new(block_height, da_block_height),
-            groups,
+            Handler::new(block_height, da_block_height, self.on_conflict)
+                .with_old_blocks_floor(
+                    self.old_blocks_floor,
+                    self.dropped_old_transactions.clone(),
+                ),
+            Prefetch::new(groups, self.import_config.prefetch_depth),
             db,
             progress_reporter,
-        );
-        let import = |token| task.run(token);
+            self.snapshot_hash,
+        )
+        .with_apply_batch_size(self.import_config.apply_batch_size)
+        .with_map_fn(map_fn);
+        self.schedule(migration_name, num_groups, move |token| task.run(token))
+    }
+
+    /// Collects the ids of the transactions included in the blocks of the snapshot below
+    /// the `old_blocks_floor`. The transactions are keyed by their id, so their height is
+    /// only known from the blocks.
+    fn old_transactions_below_floor(&self) -> anyhow::Result<HashSet<TxId>> {
+        let mut transactions = HashSet::new();
+        let Some(floor) = self.old_blocks_floor else {
+            return Ok(transactions);
+        };
+        if let Some(groups) = self.read_table::<FuelBlocks>()? {
+            for group in groups {
+                for TableEntry { key, value, .. } in group? {
+                    if key < floor {
+                        transactions.extend(value.transactions().iter().copied());
+                    }
+                }
+            }
+        }
+        if let Some(groups) = self.read_table::<OldFuelBlocks>()? {
+            for group in groups {
+                for TableEntry { key, value, .. } in group? {
+                    if key < floor {
+                        transactions.extend(value.transactions().iter().copied());
+                    }
+                }
+            }
+        }
+        Ok(transactions)
+    }
+
+    /// Reads the groups of the table, or returns `None` if the snapshot predates it.
+    fn read_table<T>(&self) -> anyhow::Result<Option<Groups<T>>>
+    where
+        T: TableWithBlueprint,
+        StateConfig: AsTable<T>,
+        TableEntry<T>: serde::de::DeserializeOwned,
+    {
+        match self.snapshot_reader.read_required::<T>() {
+            Ok(groups) => Ok(Some(groups)),
+            Err(err) => match err.downcast_ref::<SnapshotError>() {
+                Some(SnapshotError::TableNotInSnapshot { name }) => {
+                    tracing::info!("Skipping `{name}`, the snapshot predates the table");
+                    Ok(None)
+                }
+                _ => Err(err),
+            },
+        }
+    }
+
+    fn schedule<F>(
+        &mut self,
+        migration_name: String,
+        num_groups: usize,
+        import: F,
+    ) -> anyhow::Result<()>
+    where
+        F: FnOnce(CancellationToken) -> anyhow::Result<u64> + Send + 'static,
+    {
+        self.status.table_scheduled();
+        self.unfinished_tables
+            .lock()
+            .expect("poisoned")
+            .insert(migration_name.clone());
+        let status = self.status.clone();
+        let unfinished_tables = self.unfinished_tables.clone();
+        let import = move |token| {
+            import(token).map(|rows| {
+                status.table_done(rows);
+                unfinished_tables
+                    .lock()
+                    .expect("poisoned")
+                    .remove(&migration_name);
+                (migration_name, rows)
+            })
+        };
         if num_groups < GROUPS_NUMBER_FOR_PARALLELIZATION {
-            self.task_manager.run(import)?;
+            let (migration_name, rows) = self.task_manager.run(import)?;
+            self.summary.record(migration_name, rows);
         } else {
             self.task_manager.spawn_blocking(import);
         }
@@ -233,23 +694,176 @@ impl SnapshotImporter {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+async fn wait_for_workers(
+    task_manager: TaskManager<(String, u64)>,
+    unfinished_tables: &Mutex<BTreeSet<String>>,
+    timeout: Option<Duration>,
+) -> anyhow::Result<Vec<(String, u64)>> {
+    let Some(timeout) = timeout else {
+        return task_manager.wait().await
+    };
+    match task_manager.wait_for(timeout).await {
+        Some(results) => results,
+        None => {
+            let tables = unfinished_tables
+                .lock()
+                .expect("poisoned")
+                .iter()
+                .cloned()
+                .collect();
+            Err(SnapshotError::ImportTimeout { tables }.into())
+        }
+    }
+}
+
+/// Cross-checks the imported `Messages` and `SpentMessages` tables.
+///
+/// Consumed messages are removed from `Messages`, so a spent message without a row there
+/// is expected. A message that is still unspent while also marked as spent is reported
+/// as [`SnapshotError::InconsistentMessageState`].
+pub fn validate_message_state(db: &CombinedGenesisDatabase) -> anyhow::Result<()> {
+    let mut nonces = vec![];
+    for entry in db.off_chain().iter_all::<SpentMessages>(None) {
+        let (nonce, _) = entry?;
+        if db.on_chain().storage::<Messages>().contains_key(&nonce)? {
+            nonces.push(nonce);
+        }
+    }
+
+    if nonces.is_empty() {
+        Ok(())
+    } else {
+        Err(SnapshotError::InconsistentMessageState { nonces }.into())
+    }
+}
+
+/// Cross-checks the imported historical blocks and their consensus.
+///
+/// Both `OldFuelBlocks` and `OldFuelBlockConsensus` are keyed by the block height, so
+/// every block is expected to have a consensus at the same height and vice versa. The
+/// mismatches are reported as [`SnapshotError::OrphanConsensus`].
+pub fn validate_block_consensus(db: &CombinedGenesisDatabase) -> anyhow::Result<()> {
+    let blocks = db
+        .off_chain()
+        .iter_all::<OldFuelBlocks>(None)
+        .map(|entry| entry.map(|(height, _)| height))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+    let consensus = db
+        .off_chain()
+        .iter_all::<OldFuelBlockConsensus>(None)
+        .map(|entry| entry.map(|(height, _)| height))
+        .collect::<Result<BTreeSet<_>, _>>()?;
+
+    let blocks_without_consensus: Vec<_> =
+        blocks.difference(&consensus).copied().collect();
+    let consensus_without_block: Vec<_> =
+        consensus.difference(&blocks).copied().collect();
+    if blocks_without_consensus.is_empty() && consensus_without_block.is_empty() {
+        Ok(())
+    } else {
+        Err(SnapshotError::OrphanConsensus {
+            blocks_without_consensus,
+            consensus_without_block,
+        }
+        .into())
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Handler<TableBeingWritten, TableInSnapshot> {
     pub block_height: BlockHeight,
     pub da_block_height: DaBlockHeight,
+    pub on_conflict: ConflictPolicy,
+    /// The historical blocks and their consensus below this height aren't imported.
+    pub old_blocks_floor: Option<BlockHeight>,
+    /// The historical transactions that aren't imported, because their blocks are below
+    /// the `old_blocks_floor`.
+    pub dropped_old_transactions: Arc<HashSet<TxId>>,
     _table_being_written: PhantomData<TableBeingWritten>,
     _table_in_snapshot: PhantomData<TableInSnapshot>,
 }
 
 impl<A, B> Handler<A, B> {
-    pub fn new(block_height: BlockHeight, da_block_height: DaBlockHeight) -> Self {
+    pub fn new(
+        block_height: BlockHeight,
+        da_block_height: DaBlockHeight,
+        on_conflict: ConflictPolicy,
+    ) -> Self {
         Self {
             block_height,
             da_block_height,
+            on_conflict,
+            old_blocks_floor: None,
+            dropped_old_transactions: Default::default(),
             _table_being_written: PhantomData,
             _table_in_snapshot: PhantomData,
         }
     }
+
+    pub fn with_old_blocks_floor(
+        mut self,
+        old_blocks_floor: Option<BlockHeight>,
+        dropped_old_transactions: Arc<HashSet<TxId>>,
+    ) -> Self {
+        self.old_blocks_floor = old_blocks_floor;
+        self.dropped_old_transactions = dropped_old_transactions;
+        self
+    }
+
+    /// Applies the `on_conflict` policy to the row of `T` at `key` that is about to be
+    /// written. Returns whether the row should be written, or an error if the row
+    /// already exists and the policy forbids replacing it.
+    pub fn admits<T, S>(&self, storage: &S, key: &T::Key) -> anyhow::Result<bool>
+    where
+        T: TableWithBlueprint,
+        S: StorageInspect<T, Error = fuel_core_storage::Error>,
+    {
+        if self.on_conflict == ConflictPolicy::Overwrite || !storage.contains_key(key)? {
+            return Ok(true);
+        }
+        match self.on_conflict {
+            ConflictPolicy::Skip => Ok(false),
+            ConflictPolicy::Overwrite | ConflictPolicy::Error => Err(anyhow::anyhow!(
+                "The row of the {} table already exists",
+                T::column().name()
+            )),
+        }
+    }
+
+    /// Keeps the entries of the `group` admitted by the `on_conflict` policy, where
+    /// `key_of` gives the key of the row each entry writes to the table `T`.
+    pub fn retain_admitted<T, S, E, K>(
+        &self,
+        storage: &S,
+        group: Vec<E>,
+        key_of: impl Fn(&E) -> K,
+    ) -> anyhow::Result<Vec<E>>
+    where
+        T: TableWithBlueprint,
+        S: StorageInspect<T, Error = fuel_core_storage::Error>,
+        K: Borrow<T::Key>,
+    {
+        if self.on_conflict == ConflictPolicy::Overwrite {
+            return Ok(group);
+        }
+        let mut admitted = Vec::with_capacity(group.len());
+        for entry in group {
+            if self.admits::<T, S>(storage, key_of(&entry).borrow())? {
+                admitted.push(entry);
+            }
+        }
+        Ok(admitted)
+    }
+
+    /// Whether the historical block at `height` is kept by the `old_blocks_floor`.
+    pub fn retains_old_block(&self, height: &BlockHeight) -> bool {
+        self.old_blocks_floor.map_or(true, |floor| *height >= floor)
+    }
+
+    /// Whether the historical transaction `tx_id` is kept by the `old_blocks_floor`.
+    pub fn retains_old_transaction(&self, tx_id: &TxId) -> bool {
+        !self.dropped_old_transactions.contains(tx_id)
+    }
 }
 
 pub fn migration_name<TableInSnapshot, TableBeingWritten>() -> String
@@ -263,3 +877,915 @@ where
         TableBeingWritten::column().name()
     )
 }
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::state::StateInitializer,
+        service::genesis::progress::ImportStatus,
+    };
+    use fuel_core_chain_config::{
+        ChainConfig,
+        CoinConfig,
+        ContractConfig,
+        MessageConfig,
+        Randomize,
+    };
+    use fuel_core_storage::{
+        transactional::{
+            AtomicView,
+            WriteTransaction,
+        },
+        StorageAsMut,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    #[tokio::test]
+    async fn import__summary_counts_rows_per_table() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let coins = std::iter::repeat_with(|| CoinConfig {
+            tx_pointer_block_height: 0.into(),
+            ..Randomize::randomize(&mut rng)
+        })
+        .take(3)
+        .collect();
+        let messages = std::iter::repeat_with(|| MessageConfig {
+            da_height: DaBlockHeight(0),
+            ..Randomize::randomize(&mut rng)
+        })
+        .take(2)
+        .collect();
+        let state = StateConfig {
+            coins,
+            messages,
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+
+        // when
+        let summary = SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await
+        .unwrap();
+
+        // then
+        let expected = BTreeMap::from([
+            (migration_name::<Coins, Coins>(), 3),
+            (migration_name::<Coins, OwnedCoins>(), 3),
+            (migration_name::<Messages, Messages>(), 2),
+            (migration_name::<Messages, OwnedMessageIds>(), 2),
+        ]);
+        assert_eq!(summary.per_table, expected);
+        assert_eq!(summary.total_rows, 10);
+    }
+
+    #[tokio::test]
+    async fn import__entry_map_dropping_every_other_coin_imports_half_the_coins() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let coins = std::iter::repeat_with(|| given_coin(&mut rng, 1))
+            .take(10)
+            .collect();
+        let state = StateConfig {
+            coins,
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+        let db = CombinedGenesisDatabase::default();
+        let mut keep = false;
+        let entry_maps =
+            EntryMaps::default().with::<Coins, Coins>(Box::new(move |entry| {
+                keep = !keep;
+                keep.then_some(entry)
+            }));
+
+        // when
+        let summary = SnapshotImporter::import(
+            db.clone(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            entry_maps,
+        )
+        .await
+        .unwrap();
+
+        // then
+        assert_eq!(db.on_chain().iter_all::<Coins>(None).count(), 5);
+        assert_eq!(summary.per_table[&migration_name::<Coins, Coins>()], 5);
+        // The entry map of a migration doesn't affect the other migrations of the table
+        assert_eq!(db.off_chain().iter_all::<OwnedCoins>(None).count(), 10);
+    }
+
+    #[tokio::test]
+    async fn import__with_custom_import_config_imports_all_rows() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = StateConfig {
+            coins: vec![given_coin(&mut rng, 1), given_coin(&mut rng, 1)],
+            messages: vec![MessageConfig {
+                da_height: DaBlockHeight(0),
+                ..Randomize::randomize(&mut rng)
+            }],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+        let import_config = GenesisImportConfig {
+            max_concurrent_tables: Some(1),
+            apply_batch_size: Some(1),
+            prefetch_depth: 0,
+            lock_file: None,
+        };
+
+        // when
+        let summary = SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            import_config,
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await
+        .unwrap();
+
+        // then
+        assert_eq!(summary.total_rows, 6);
+    }
+
+    #[tokio::test]
+    async fn import__without_off_chain_imports_only_on_chain_tables() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = StateConfig {
+            coins: vec![given_coin(&mut rng, 1), given_coin(&mut rng, 1)],
+            messages: vec![MessageConfig {
+                da_height: DaBlockHeight(0),
+                ..Randomize::randomize(&mut rng)
+            }],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+        let db = CombinedGenesisDatabase::default();
+
+        // when
+        let summary = SnapshotImporter::import(
+            db.clone(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            GenesisImportConfig::default(),
+            false,
+            None,
+            EntryMaps::default(),
+        )
+        .await
+        .unwrap();
+
+        // then
+        let expected = BTreeMap::from([
+            (migration_name::<Coins, Coins>(), 2),
+            (migration_name::<Messages, Messages>(), 1),
+        ]);
+        assert_eq!(summary.per_table, expected);
+        assert_eq!(db.off_chain().iter_all::<OwnedCoins>(None).count(), 0);
+    }
+
+    #[tokio::test]
+    async fn import__status_is_complete_after_import() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = StateConfig {
+            coins: vec![given_coin(&mut rng, 1), given_coin(&mut rng, 1)],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+        let status = ImportStatusTracker::default();
+
+        // when
+        SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            status.clone(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await
+        .unwrap();
+
+        // then
+        let expected = ImportStatus {
+            in_progress: false,
+            tables_done: 2,
+            tables_total: 2,
+            rows_applied: 4,
+        };
+        assert_eq!(status.status(), expected);
+    }
+
+    #[tokio::test]
+    async fn import__tables_without_groups_are_not_scheduled() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = StateConfig {
+            coins: vec![given_coin(&mut rng, 1)],
+            messages: vec![],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+        let status = ImportStatusTracker::default();
+
+        // when
+        let summary = SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            status.clone(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await
+        .unwrap();
+
+        // then
+        assert!(!summary
+            .per_table
+            .contains_key(&migration_name::<Messages, Messages>()));
+        assert!(!summary
+            .per_table
+            .contains_key(&migration_name::<Messages, OwnedMessageIds>()));
+        assert_eq!(status.status().tables_total, 2);
+        assert_eq!(status.status().tables_done, 2);
+    }
+
+    #[tokio::test]
+    async fn import__empty_snapshot_spawns_no_workers() {
+        // given
+        let reader = SnapshotReader::new_in_memory(
+            ChainConfig::local_testnet(),
+            StateConfig::default(),
+        );
+        let status = ImportStatusTracker::default();
+
+        // when
+        let summary = SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            status.clone(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await
+        .unwrap();
+
+        // then
+        assert_eq!(summary, ImportSummary::default());
+        assert_eq!(status.status(), ImportStatus::default());
+    }
+
+    #[tokio::test]
+    async fn import_to_memory__imported_contract_can_be_queried() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let contract = given_contract(&mut rng, 1, vec![1; 16]);
+        let state = StateConfig {
+            contracts: vec![contract.clone()],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+
+        // when
+        let db = SnapshotImporter::import_to_memory(Block::default(), reader)
+            .await
+            .unwrap();
+
+        // then
+        let code = db
+            .on_chain()
+            .latest_view()
+            .unwrap()
+            .contract_code(contract.contract_id)
+            .unwrap();
+        assert_eq!(Vec::<u8>::from(code.value), contract.code);
+    }
+
+    fn given_contract(rng: &mut StdRng, id: u8, code: Vec<u8>) -> ContractConfig {
+        ContractConfig {
+            contract_id: [id; 32].into(),
+            code,
+            tx_pointer_block_height: 0.into(),
+            states: vec![],
+            balances: vec![],
+            ..Randomize::randomize(rng)
+        }
+    }
+
+    #[tokio::test]
+    async fn import_with_delta__delta_overrides_the_base() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let base = StateConfig {
+            contracts: vec![given_contract(&mut rng, 1, vec![1; 8])],
+            ..Default::default()
+        };
+        let updated_a = given_contract(&mut rng, 1, vec![2; 16]);
+        let new_b = given_contract(&mut rng, 2, vec![3; 4]);
+        let delta = StateConfig {
+            contracts: vec![updated_a.clone(), new_b.clone()],
+            ..Default::default()
+        };
+        let db = CombinedGenesisDatabase::default();
+
+        // when
+        SnapshotImporter::import_with_delta(
+            db.clone(),
+            Block::default(),
+            SnapshotReader::new_in_memory(ChainConfig::local_testnet(), base),
+            SnapshotReader::new_in_memory(ChainConfig::local_testnet(), delta),
+            StateWatcher::started(),
+        )
+        .await
+        .unwrap();
+
+        // then
+        let code_of = |contract: &ContractConfig| {
+            db.on_chain()
+                .storage_as_ref::<ContractsRawCode>()
+                .get(&contract.contract_id)
+                .unwrap()
+                .map(|code| Vec::<u8>::from(code.into_owned()))
+        };
+        assert_eq!(code_of(&updated_a), Some(vec![2; 16]));
+        assert_eq!(code_of(&new_b), Some(vec![3; 4]));
+        let utxo = db
+            .on_chain()
+            .storage_as_ref::<ContractsLatestUtxo>()
+            .get(&updated_a.contract_id)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(utxo.utxo_id(), &updated_a.utxo_id());
+    }
+
+    #[tokio::test]
+    async fn import__fails_fast_while_another_import_holds_the_lock() {
+        // given
+        let lock_file = std::env::temp_dir().join(format!(
+            "fuel_core_genesis_import_{}.lock",
+            std::process::id()
+        ));
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = StateConfig {
+            coins: vec![given_coin(&mut rng, 1)],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+        let import_config = GenesisImportConfig {
+            lock_file: Some(lock_file.clone()),
+            ..Default::default()
+        };
+        let running_import = ImportLock::acquire(lock_file.clone()).unwrap();
+
+        // when
+        let result = SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader.clone(),
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            import_config.clone(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await;
+
+        // then
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SnapshotError>(),
+            Some(&SnapshotError::ImportAlreadyInProgress {
+                lock_file: lock_file.clone()
+            })
+        );
+        drop(running_import);
+        let summary = SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            import_config,
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(summary.per_table[&migration_name::<Coins, Coins>()], 1);
+        std::fs::remove_file(lock_file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn import__ignores_the_lock_file_left_by_a_crashed_import() {
+        // given
+        let lock_file = std::env::temp_dir().join(format!(
+            "fuel_core_genesis_import_stale_{}.lock",
+            std::process::id()
+        ));
+        std::fs::write(&lock_file, "1").unwrap();
+        let mut rng = StdRng::seed_from_u64(10);
+        let state = StateConfig {
+            coins: vec![given_coin(&mut rng, 1)],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+        let import_config = GenesisImportConfig {
+            lock_file: Some(lock_file.clone()),
+            ..Default::default()
+        };
+
+        // when
+        let result = SnapshotImporter::import(
+            CombinedGenesisDatabase::default(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            ConflictPolicy::default(),
+            ImportStatusTracker::default(),
+            None,
+            import_config,
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await;
+
+        // then
+        let summary = result.unwrap();
+        assert_eq!(summary.per_table[&migration_name::<Coins, Coins>()], 1);
+        std::fs::remove_file(lock_file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn wait_for_workers__timeout_names_unfinished_tables() {
+        // given
+        let mut task_manager = TaskManager::new(StateWatcher::started());
+        task_manager.spawn_blocking(|token| {
+            while !token.is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            Ok(("slow".to_string(), 0))
+        });
+        let unfinished_tables = Mutex::new(BTreeSet::from(["slow".to_string()]));
+
+        // when
+        let result = wait_for_workers(
+            task_manager,
+            &unfinished_tables,
+            Some(Duration::from_millis(10)),
+        )
+        .await;
+
+        // then
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SnapshotError>(),
+            Some(&SnapshotError::ImportTimeout {
+                tables: vec!["slow".to_string()]
+            })
+        );
+    }
+
+    fn given_coin(rng: &mut StdRng, amount: u64) -> CoinConfig {
+        CoinConfig {
+            tx_pointer_block_height: 0.into(),
+            amount,
+            ..Randomize::randomize(rng)
+        }
+    }
+
+    // Imports an updated version of `existing` together with `new` on top of a database
+    // that already contains `existing`.
+    async fn reimport_coins(
+        on_conflict: ConflictPolicy,
+        existing: &CoinConfig,
+        new: &CoinConfig,
+    ) -> (CombinedGenesisDatabase, anyhow::Result<ImportSummary>) {
+        let db = CombinedGenesisDatabase::default();
+        let mut on_chain = db.on_chain().clone();
+        let mut tx = on_chain.write_transaction();
+        let entry = TableEntry::<Coins>::from(existing.clone());
+        tx.storage_as_mut::<Coins>()
+            .insert(&entry.key, &entry.value)
+            .unwrap();
+        tx.commit().unwrap();
+
+        let updated = CoinConfig {
+            amount: existing.amount.saturating_add(1),
+            ..existing.clone()
+        };
+        let state = StateConfig {
+            coins: vec![updated, new.clone()],
+            ..Default::default()
+        };
+        let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+
+        let result = SnapshotImporter::import(
+            db.clone(),
+            Block::default(),
+            reader,
+            StateWatcher::started(),
+            on_conflict,
+            ImportStatusTracker::default(),
+            None,
+            GenesisImportConfig::default(),
+            true,
+            None,
+            EntryMaps::default(),
+        )
+        .await;
+        (db, result)
+    }
+
+    fn coin_amount(db: &CombinedGenesisDatabase, coin: &CoinConfig) -> Option<u64> {
+        db.on_chain()
+            .storage_as_ref::<Coins>()
+            .get(&coin.utxo_id())
+            .unwrap()
+            .map(|coin| *coin.amount())
+    }
+
+    #[tokio::test]
+    async fn import__overwrite_policy_replaces_existing_rows() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let existing = given_coin(&mut rng, 1);
+        let new = given_coin(&mut rng, 1);
+
+        // when
+        let (db, result) =
+            reimport_coins(ConflictPolicy::Overwrite, &existing, &new).await;
+
+        // then
+        result.unwrap();
+        assert_eq!(coin_amount(&db, &existing), Some(2));
+        assert_eq!(coin_amount(&db, &new), Some(1));
+    }
+
+    #[tokio::test]
+    async fn import__skip_policy_keeps_existing_rows() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let existing = given_coin(&mut rng, 1);
+        let new = given_coin(&mut rng, 1);
+
+        // when
+        let (db, result) = reimport_coins(ConflictPolicy::Skip, &existing, &new).await;
+
+        // then
+        result.unwrap();
+        assert_eq!(coin_amount(&db, &existing), Some(1));
+        assert_eq!(coin_amount(&db, &new), Some(1));
+    }
+
+    #[tokio::test]
+    async fn import__error_policy_rejects_existing_rows() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let existing = given_coin(&mut rng, 1);
+        let new = given_coin(&mut rng, 1);
+
+        // when
+        let (db, result) = reimport_coins(ConflictPolicy::Error, &existing, &new).await;
+
+        // then
+        assert!(result.is_err());
+        assert_eq!(coin_amount(&db, &existing), Some(1));
+        assert_eq!(coin_amount(&db, &new), None);
+    }
+
+    #[test]
+    fn handler__old_blocks_below_the_floor_are_not_imported() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let blocks = (1u32..=4)
+            .map(|height| TableEntry::<OldFuelBlocks> {
+                key: height.into(),
+                value: Randomize::randomize(&mut rng),
+            })
+            .collect();
+        let mut handler = Handler::<OldFuelBlocks, OldFuelBlocks>::new(
+            0.into(),
+            DaBlockHeight(0),
+            ConflictPolicy::default(),
+        )
+        .with_old_blocks_floor(Some(3.into()), Default::default());
+        let mut db = CombinedGenesisDatabase::default().off_chain().clone();
+
+        // when
+        let mut tx = db.write_transaction();
+        handler.process(blocks, &mut tx).unwrap();
+        tx.commit().unwrap();
+
+        // then
+        let imported: Vec<BlockHeight> = db
+            .iter_all::<OldFuelBlocks>(None)
+            .map(|entry| entry.unwrap().0)
+            .collect();
+        assert_eq!(imported, vec![3.into(), 4.into()]);
+    }
+
+    #[test]
+    fn handler__old_transactions_of_dropped_blocks_are_not_imported() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let kept = TableEntry::<OldTransactions> {
+            key: Randomize::randomize(&mut rng),
+            value: Randomize::randomize(&mut rng),
+        };
+        let dropped = TableEntry::<OldTransactions> {
+            key: Randomize::randomize(&mut rng),
+            value: Randomize::randomize(&mut rng),
+        };
+        let mut handler = Handler::<OldTransactions, OldTransactions>::new(
+            0.into(),
+            DaBlockHeight(0),
+            ConflictPolicy::default(),
+        )
+        .with_old_blocks_floor(Some(3.into()), Arc::new(HashSet::from([dropped.key])));
+        let mut db = CombinedGenesisDatabase::default().off_chain().clone();
+
+        // when
+        let mut tx = db.write_transaction();
+        handler
+            .process(vec![kept.clone(), dropped.clone()], &mut tx)
+            .unwrap();
+        tx.commit().unwrap();
+
+        // then
+        let imported: Vec<TxId> = db
+            .iter_all::<OldTransactions>(None)
+            .map(|entry| entry.unwrap().0)
+            .collect();
+        assert_eq!(imported, vec![kept.key]);
+    }
+
+    #[test]
+    fn handler__skip_policy_keeps_existing_contract_state() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let existing = TableEntry::<ContractsState> {
+            key: Randomize::randomize(&mut rng),
+            value: Randomize::randomize(&mut rng),
+        };
+        let updated = TableEntry::<ContractsState> {
+            key: existing.key,
+            value: Randomize::randomize(&mut rng),
+        };
+        let new = TableEntry::<ContractsState> {
+            key: Randomize::randomize(&mut rng),
+            value: Randomize::randomize(&mut rng),
+        };
+        let mut db = CombinedGenesisDatabase::default().on_chain().clone();
+        let mut tx = db.write_transaction();
+        tx.update_contract_states(vec![existing.clone()]).unwrap();
+        tx.commit().unwrap();
+        let mut handler = Handler::<ContractsState, ContractsState>::new(
+            0.into(),
+            DaBlockHeight(0),
+            ConflictPolicy::Skip,
+        );
+
+        // when
+        let mut tx = db.write_transaction();
+        handler
+            .process(vec![updated, new.clone()], &mut tx)
+            .unwrap();
+        tx.commit().unwrap();
+
+        // then
+        let value_of = |key| {
+            db.storage_as_ref::<ContractsState>()
+                .get(key)
+                .unwrap()
+                .unwrap()
+                .into_owned()
+        };
+        assert_eq!(value_of(&existing.key), existing.value);
+        assert_eq!(value_of(&new.key), new.value);
+    }
+
+    #[test]
+    fn handler__error_policy_rejects_existing_processed_transactions() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let existing = TableEntry::<ProcessedTransactions> {
+            key: Randomize::randomize(&mut rng),
+            value: (),
+        };
+        let mut db = CombinedGenesisDatabase::default().on_chain().clone();
+        let mut tx = db.write_transaction();
+        tx.storage_as_mut::<ProcessedTransactions>()
+            .insert(&existing.key, &())
+            .unwrap();
+        tx.commit().unwrap();
+        let mut handler = Handler::<ProcessedTransactions, ProcessedTransactions>::new(
+            0.into(),
+            DaBlockHeight(0),
+            ConflictPolicy::Error,
+        );
+
+        // when
+        let mut tx = db.write_transaction();
+        let result = handler.process(vec![existing], &mut tx);
+
+        // then
+        assert!(result.is_err());
+    }
+
+    fn given_imported_messages(
+        unspent: &[TableEntry<Messages>],
+        spent: &[TableEntry<Messages>],
+    ) -> CombinedGenesisDatabase {
+        let db = CombinedGenesisDatabase::default();
+        let mut on_chain = db.on_chain().clone();
+        let mut tx = on_chain.write_transaction();
+        for entry in unspent {
+            tx.storage_as_mut::<Messages>()
+                .insert(&entry.key, &entry.value)
+                .unwrap();
+        }
+        tx.commit().unwrap();
+        let mut off_chain = db.off_chain().clone();
+        let mut tx = off_chain.write_transaction();
+        for entry in spent {
+            tx.storage_as_mut::<SpentMessages>()
+                .insert(&entry.key, &())
+                .unwrap();
+        }
+        tx.commit().unwrap();
+        db
+    }
+
+    #[test]
+    fn validate_message_state__unspent_message_marked_as_spent_is_an_error() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let message = TableEntry::<Messages>::randomize(&mut rng);
+        let db = given_imported_messages(&[message.clone()], &[message.clone()]);
+
+        // when
+        let result = validate_message_state(&db);
+
+        // then
+        let err = result.expect_err("Expected the message to be inconsistent");
+        assert_eq!(
+            err.downcast_ref::<SnapshotError>(),
+            Some(&SnapshotError::InconsistentMessageState {
+                nonces: vec![message.key]
+            })
+        );
+    }
+
+    fn given_imported_old_blocks(
+        blocks: &[BlockHeight],
+        consensus: &[BlockHeight],
+    ) -> CombinedGenesisDatabase {
+        let db = CombinedGenesisDatabase::default();
+        let mut off_chain = db.off_chain().clone();
+        let mut tx = off_chain.write_transaction();
+        for height in blocks {
+            tx.storage_as_mut::<OldFuelBlocks>()
+                .insert(height, &Default::default())
+                .unwrap();
+        }
+        for height in consensus {
+            tx.storage_as_mut::<OldFuelBlockConsensus>()
+                .insert(height, &Default::default())
+                .unwrap();
+        }
+        tx.commit().unwrap();
+        db
+    }
+
+    #[test]
+    fn validate_block_consensus__consensus_without_block_is_an_error() {
+        // given
+        let db = given_imported_old_blocks(
+            &[1.into(), 2.into()],
+            &[1.into(), 2.into(), 3.into()],
+        );
+
+        // when
+        let result = validate_block_consensus(&db);
+
+        // then
+        let err = result.expect_err("Expected the consensus to be orphaned");
+        assert_eq!(
+            err.downcast_ref::<SnapshotError>(),
+            Some(&SnapshotError::OrphanConsensus {
+                blocks_without_consensus: vec![],
+                consensus_without_block: vec![3.into()],
+            })
+        );
+    }
+
+    #[test]
+    fn validate_block_consensus__block_without_consensus_is_an_error() {
+        // given
+        let db = given_imported_old_blocks(&[1.into(), 2.into()], &[2.into()]);
+
+        // when
+        let result = validate_block_consensus(&db);
+
+        // then
+        let err = result.expect_err("Expected the block to miss its consensus");
+        assert_eq!(
+            err.downcast_ref::<SnapshotError>(),
+            Some(&SnapshotError::OrphanConsensus {
+                blocks_without_consensus: vec![1.into()],
+                consensus_without_block: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn validate_block_consensus__matching_blocks_and_consensus_are_consistent() {
+        // given
+        let db = given_imported_old_blocks(&[1.into(), 2.into()], &[1.into(), 2.into()]);
+
+        // when
+        let result = validate_block_consensus(&db);
+
+        // then
+        result.expect("Every block has its consensus");
+    }
+
+    #[test]
+    fn validate_message_state__consumed_message_without_row_is_consistent() {
+        // given
+        let mut rng = StdRng::seed_from_u64(10);
+        let unspent = TableEntry::<Messages>::randomize(&mut rng);
+        let consumed = TableEntry::<Messages>::randomize(&mut rng);
+        let db = given_imported_messages(&[unspent], &[consumed]);
+
+        // when
+        let result = validate_message_state(&db);
+
+        // then
+        result.expect("Consumed messages are removed from `Messages`");
+    }
+}
@@ -7,17 +7,20 @@ use fuel_core_storage::{
         StorageTransaction,
         WriteTransaction,
     },
+    StorageAsMut,
     StorageAsRef,
     StorageInspect,
     StorageMutate,
 };
+use fuel_core_types::fuel_types::Bytes32;
+use itertools::Itertools;
 
 use crate::{
     database::{
         database_description::DatabaseDescription,
         genesis_progress::{
             GenesisMetadata,
-            GenesisProgressMutate,
+            GenesisProgress,
         },
         GenesisDatabase,
     },
@@ -29,12 +32,22 @@ use crate::{
 
 use super::migration_name;
 
+/// Observes or transforms an entry of the snapshot before it is imported. Returning
+/// `None` drops the entry.
+pub type EntryMapFn<T> = Box<dyn FnMut(TableEntry<T>) -> Option<TableEntry<T>> + Send>;
+
 pub struct ImportTask<Handler, Groups, DbDesc>
 where
     DbDesc: DatabaseDescription,
+    Handler: ImportTable,
 {
     handler: Handler,
+    snapshot_hash: Bytes32,
     skip: usize,
+    skip_entries: usize,
+    stale_checkpoint: bool,
+    apply_batch_size: Option<usize>,
+    map_fn: Option<EntryMapFn<Handler::TableInSnapshot>>,
     groups: Groups,
     db: GenesisDatabase<DbDesc>,
     reporter: ProgressReporter,
@@ -58,29 +71,61 @@ where
     Logic: ImportTable<DbDesc = DbDesc>,
     GenesisDatabase<DbDesc>: StorageInspect<GenesisMetadata<DbDesc>>,
 {
+    /// Creates a task importing `groups` of the snapshot identified by `snapshot_hash`.
+    /// The import resumes after the last checkpointed entry, unless the checkpoint was
+    /// made for a different snapshot.
     pub fn new(
         handler: Logic,
         groups: GroupGenerator,
         db: GenesisDatabase<DbDesc>,
         reporter: ProgressReporter,
+        snapshot_hash: Bytes32,
     ) -> Self {
         let progress_name =
             migration_name::<Logic::TableInSnapshot, Logic::TableBeingWritten>();
-        let skip = match db.storage::<GenesisMetadata<DbDesc>>().get(&progress_name) {
-            Ok(Some(idx_last_handled)) => {
-                usize::saturating_add(idx_last_handled.into_owned(), 1)
-            }
-            _ => 0,
-        };
+        let (skip, skip_entries, stale_checkpoint) =
+            match db.storage::<GenesisMetadata<DbDesc>>().get(&progress_name) {
+                Ok(Some(progress)) if progress.snapshot_hash == snapshot_hash => {
+                    match progress.applied_entries {
+                        Some(applied) => (progress.processed_group, applied, false),
+                        None => (progress.processed_group.saturating_add(1), 0, false),
+                    }
+                }
+                Ok(Some(_)) => (0, 0, true),
+                _ => (0, 0, false),
+            };
 
         Self {
             handler,
+            snapshot_hash,
             skip,
+            skip_entries,
+            stale_checkpoint,
+            apply_batch_size: None,
+            map_fn: None,
             groups,
             db,
             reporter,
         }
     }
+
+    /// Applies the entries of each group in write batches of at most `apply_batch_size`
+    /// entries instead of one batch per group.
+    pub fn with_apply_batch_size(mut self, apply_batch_size: Option<usize>) -> Self {
+        self.apply_batch_size = apply_batch_size;
+        self
+    }
+
+    /// Passes every entry through `map_fn` before it is handed to the handler. The
+    /// entries dropped by `map_fn` are still checkpointed as processed, but aren't
+    /// counted as applied.
+    pub fn with_map_fn(
+        mut self,
+        map_fn: Option<EntryMapFn<Logic::TableInSnapshot>>,
+    ) -> Self {
+        self.map_fn = map_fn;
+        self
+    }
 }
 
 impl<Logic, GroupGenerator, DbDesc> ImportTask<Logic, GroupGenerator, DbDesc>
@@ -92,17 +137,28 @@ where
     GenesisMetadata<DbDesc>: TableWithBlueprint<
         Column = DbDesc::Column,
         Key = str,
-        Value = usize,
-        OwnedValue = usize,
+        Value = GenesisProgress,
+        OwnedValue = GenesisProgress,
     >,
     GenesisDatabase<DbDesc>:
         StorageInspect<GenesisMetadata<DbDesc>> + WriteTransaction + Modifiable,
     for<'a> StorageTransaction<&'a mut GenesisDatabase<DbDesc>>:
         StorageMutate<GenesisMetadata<DbDesc>, Error = fuel_core_storage::Error>,
 {
-    pub fn run(mut self, cancel_token: CancellationToken) -> anyhow::Result<()> {
+    /// Imports the remaining groups and returns the number of entries applied.
+    pub fn run(mut self, cancel_token: CancellationToken) -> anyhow::Result<u64> {
         let mut db = self.db;
+        let progress_name =
+            migration_name::<Logic::TableInSnapshot, Logic::TableBeingWritten>();
+        if self.stale_checkpoint {
+            let mut tx = db.write_transaction();
+            tx.storage_as_mut::<GenesisMetadata<DbDesc>>()
+                .remove(&progress_name)?;
+            tx.commit()?;
+        }
         let mut is_cancelled = cancel_token.is_cancelled();
+        let mut rows: u64 = 0;
+        let first_group = self.skip;
         self.groups
             .into_iter()
             .enumerate()
@@ -113,15 +169,49 @@ where
             })
             .try_for_each(|(index, group)| {
                 let group = group?;
-                let mut tx = db.write_transaction();
-                self.handler.process(group, &mut tx)?;
-
-                GenesisProgressMutate::<DbDesc>::update_genesis_progress(
-                    &mut tx,
-                    &migration_name::<Logic::TableInSnapshot, Logic::TableBeingWritten>(),
-                    index,
-                )?;
-                tx.commit()?;
+                let group_len = group.len();
+                let mut applied = if index == first_group {
+                    self.skip_entries.min(group_len)
+                } else {
+                    0
+                };
+                let entries = group.into_iter().skip(applied).collect_vec();
+                let batch_size = self.apply_batch_size.unwrap_or(group_len).max(1);
+                let batches = if entries.is_empty() {
+                    vec![vec![]]
+                } else {
+                    entries
+                        .into_iter()
+                        .chunks(batch_size)
+                        .into_iter()
+                        .map(Iterator::collect)
+                        .collect_vec()
+                };
+
+                for batch in batches {
+                    let batch_len = batch.len();
+                    let batch = match self.map_fn.as_mut() {
+                        Some(map_fn) => {
+                            batch.into_iter().filter_map(map_fn).collect_vec()
+                        }
+                        None => batch,
+                    };
+                    let mapped_len = batch.len();
+                    let mut tx = db.write_transaction();
+                    self.handler.process(batch, &mut tx)?;
+
+                    applied = applied.saturating_add(batch_len);
+                    let progress = GenesisProgress {
+                        snapshot_hash: self.snapshot_hash,
+                        processed_group: index,
+                        applied_entries: (applied < group_len).then_some(applied),
+                    };
+                    tx.storage_as_mut::<GenesisMetadata<DbDesc>>()
+                        .insert(&progress_name, &progress)?;
+                    tx.commit()?;
+                    rows = rows
+                        .saturating_add(u64::try_from(mapped_len).unwrap_or(u64::MAX));
+                }
                 self.reporter.set_index(index);
                 anyhow::Result::<_>::Ok(())
             })?;
@@ -130,7 +220,7 @@ where
             bail!("Import cancelled")
         }
 
-        Ok(())
+        Ok(rows)
     }
 }
 
@@ -138,13 +228,17 @@ where
 mod tests {
     use crate::{
         database::{
-            genesis_progress::GenesisProgressInspect,
+            genesis_progress::{
+                GenesisProgress,
+                GenesisProgressInspect,
+            },
             GenesisDatabase,
         },
         service::genesis::{
             importer::{
                 import_task::ImportTask,
                 migration_name,
+                prefetch::Prefetch,
             },
             progress::ProgressReporter,
             task_manager::CancellationToken,
@@ -169,6 +263,7 @@ mod tests {
             BoxedIter,
             IterDirection,
             IterableStore,
+            IteratorOverTable,
         },
         kv_store::{
             KVItem,
@@ -191,7 +286,10 @@ mod tests {
             CompressedCoinV1,
         },
         fuel_tx::UtxoId,
-        fuel_types::BlockHeight,
+        fuel_types::{
+            BlockHeight,
+            Bytes32,
+        },
     };
     use rand::{
         rngs::StdRng,
@@ -212,6 +310,8 @@ mod tests {
 
     use super::ImportTable;
 
+    const SNAPSHOT_HASH: Bytes32 = Bytes32::zeroed();
+
     struct TestHandler<L> {
         logic: L,
     }
@@ -291,6 +391,7 @@ mod tests {
             data.as_ok_groups(),
             GenesisDatabase::default(),
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
@@ -310,6 +411,7 @@ mod tests {
         GenesisProgressMutate::<OnChain>::update_genesis_progress(
             &mut db,
             &migration_name::<Coins, Coins>(),
+            SNAPSHOT_HASH,
             0,
         )
         .unwrap();
@@ -321,6 +423,7 @@ mod tests {
             data.as_ok_groups(),
             db,
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
@@ -359,6 +462,7 @@ mod tests {
             groups.as_ok_groups(),
             outer_db.clone(),
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
@@ -395,6 +499,7 @@ mod tests {
             groups.as_ok_groups(),
             db.clone(),
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
@@ -413,6 +518,7 @@ mod tests {
             groups.as_ok_groups(),
             Default::default(),
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
@@ -431,6 +537,7 @@ mod tests {
             groups,
             Default::default(),
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
@@ -450,19 +557,221 @@ mod tests {
             data.as_ok_groups(),
             db.clone(),
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
+        );
+
+        // when
+        runner.run(never_cancel()).unwrap();
+
+        // then
+        assert_eq!(
+            GenesisProgressInspect::<OnChain>::genesis_progress(
+                &db,
+                &migration_name::<Coins, Coins>(),
+            ),
+            Some(GenesisProgress {
+                snapshot_hash: SNAPSHOT_HASH,
+                processed_group: 1,
+                applied_entries: None,
+            })
+        );
+    }
+
+    #[test]
+    fn checkpoint_of_a_different_snapshot_is_discarded() {
+        // given
+        let data = TestData::new(2);
+        let other_snapshot = Bytes32::from([2; 32]);
+
+        let mut called_with = vec![];
+        let mut db = GenesisDatabase::<OnChain>::default();
+        GenesisProgressMutate::<OnChain>::update_genesis_progress(
+            &mut db,
+            &migration_name::<Coins, Coins>(),
+            other_snapshot,
+            0,
+        )
+        .unwrap();
+        let runner = ImportTask::new(
+            TestHandler::new(|element, _| {
+                called_with.push(element);
+                Ok(())
+            }),
+            data.as_ok_groups(),
+            db.clone(),
+            ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
         runner.run(never_cancel()).unwrap();
 
         // then
+        assert_eq!(called_with, data.as_entries(0));
+        assert_eq!(
+            GenesisProgressInspect::<OnChain>::genesis_progress(
+                &db,
+                &migration_name::<Coins, Coins>(),
+            ),
+            Some(GenesisProgress {
+                snapshot_hash: SNAPSHOT_HASH,
+                processed_group: 1,
+                applied_entries: None,
+            })
+        );
+    }
+
+    #[test]
+    fn large_group_is_applied_in_batches_of_the_configured_size() {
+        // given
+        let data = TestData::new(10);
+        let group = data.as_entries(0);
+        let db = GenesisDatabase::default();
+
+        let mut called_with = vec![];
+        let runner = ImportTask::new(
+            TestHandler::new(|element, _| {
+                called_with.push(element);
+                Ok(())
+            }),
+            vec![Ok(group.clone())],
+            db.clone(),
+            ProgressReporter::default(),
+            SNAPSHOT_HASH,
+        )
+        .with_apply_batch_size(Some(3));
+
+        // when
+        let rows = runner.run(never_cancel()).unwrap();
+
+        // then
+        assert_eq!(rows, 10);
+        assert_eq!(called_with, group);
         assert_eq!(
             GenesisProgressInspect::<OnChain>::genesis_progress(
                 &db,
                 &migration_name::<Coins, Coins>(),
             ),
-            Some(1)
+            Some(GenesisProgress {
+                snapshot_hash: SNAPSHOT_HASH,
+                processed_group: 0,
+                applied_entries: None,
+            })
+        );
+    }
+
+    #[test]
+    fn entries_dropped_by_the_map_fn_are_not_imported() {
+        // given
+        let data = TestData::new(10);
+        let db = GenesisDatabase::default();
+        let mut keep = false;
+        let runner = ImportTask::new(
+            TestHandler::new(|entry, tx| {
+                tx.storage_as_mut::<Coins>()
+                    .insert(&entry.key, &entry.value)?;
+                Ok(())
+            }),
+            data.as_ok_groups(),
+            db.clone(),
+            ProgressReporter::default(),
+            SNAPSHOT_HASH,
+        )
+        .with_map_fn(Some(Box::new(move |entry| {
+            keep = !keep;
+            keep.then_some(entry)
+        })));
+
+        // when
+        let rows = runner.run(never_cancel()).unwrap();
+
+        // then
+        assert_eq!(rows, 5);
+        assert_eq!(db.iter_all::<Coins>(None).count(), 5);
+    }
+
+    #[test]
+    fn prefetched_groups_are_read_while_the_previous_ones_are_applied() {
+        // given
+        let groups = TestData::new(10).as_groups();
+        let num_groups = groups.len();
+        let (started_sender, started_receiver) = std::sync::mpsc::channel();
+        let (read_sender, read_receiver) = std::sync::mpsc::channel();
+        // Every group after the first is only read once the previous one is being applied
+        let reader = groups.into_iter().enumerate().map(move |(index, group)| {
+            if index > 0 {
+                started_receiver.recv()?;
+                read_sender.send(())?;
+            }
+            Ok::<_, anyhow::Error>(group)
+        });
+        let mut applied: usize = 0;
+        let runner = ImportTask::new(
+            TestHandler::new(move |_, _| {
+                applied = applied.saturating_add(1);
+                let _ = started_sender.send(());
+                // Without prefetching, the next group is only read after this one is
+                // applied, so the wait times out instead of blocking forever
+                if applied < num_groups {
+                    read_receiver.recv_timeout(std::time::Duration::from_secs(10))?;
+                }
+                Ok(())
+            }),
+            Prefetch::new(reader, 2),
+            GenesisDatabase::default(),
+            ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
+
+        // when
+        let rows = runner.run(never_cancel()).unwrap();
+
+        // then
+        assert_eq!(rows, 10);
+    }
+
+    #[test]
+    fn import_resumes_after_the_last_applied_batch() {
+        // given
+        let data = TestData::new(10);
+        let group = data.as_entries(0);
+        let db = GenesisDatabase::<OnChain>::default();
+
+        let mut seen: usize = 0;
+        let failing_runner = ImportTask::new(
+            TestHandler::new(|_, _| {
+                seen = seen.saturating_add(1);
+                if seen == 5 {
+                    bail!("Some error")
+                }
+                Ok(())
+            }),
+            vec![Ok(group.clone())],
+            db.clone(),
+            ProgressReporter::default(),
+            SNAPSHOT_HASH,
+        )
+        .with_apply_batch_size(Some(3));
+        let _ = failing_runner.run(never_cancel());
+
+        let mut called_with = vec![];
+        let runner = ImportTask::new(
+            TestHandler::new(|element, _| {
+                called_with.push(element);
+                Ok(())
+            }),
+            vec![Ok(group.clone())],
+            db.clone(),
+            ProgressReporter::default(),
+            SNAPSHOT_HASH,
+        )
+        .with_apply_batch_size(Some(3));
+
+        // when
+        runner.run(never_cancel()).unwrap();
+
+        // then
+        assert_eq!(called_with, group[3..]);
     }
 
     #[tokio::test]
@@ -482,6 +791,7 @@ mod tests {
                 rx,
                 Default::default(),
                 ProgressReporter::default(),
+                SNAPSHOT_HASH,
             )
         };
 
@@ -577,6 +887,7 @@ mod tests {
             groups.as_ok_groups(),
             GenesisDatabase::new(Arc::new(BrokenTransactions::new())),
             ProgressReporter::default(),
+            SNAPSHOT_HASH,
         );
 
         // when
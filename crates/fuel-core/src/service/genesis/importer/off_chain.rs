@@ -9,9 +9,15 @@ use crate::{
     graphql_api::{
         storage::{
             blocks::FuelBlockIdsToHeights,
-            coins::OwnedCoins,
+            coins::{
+                owner_coin_id_key,
+                OwnedCoins,
+            },
             contracts::ContractsInfo,
-            messages::OwnedMessageIds,
+            messages::{
+                OwnedMessageIds,
+                OwnedMessageKey,
+            },
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
@@ -37,7 +43,13 @@ use fuel_core_storage::{
     transactional::StorageTransaction,
     StorageAsMut,
 };
-use fuel_core_types::services::executor::Event;
+use fuel_core_types::{
+    fuel_tx::{
+        field::Salt,
+        Transaction,
+    },
+    services::executor::Event,
+};
 
 use super::{
     import_task::ImportTable,
@@ -54,6 +66,10 @@ impl ImportTable for Handler<TransactionStatuses, TransactionStatuses> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<TransactionStatuses, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         for tx_status in group {
             tx.storage::<Self::TableInSnapshot>()
                 .insert(&tx_status.key, &tx_status.value)?;
@@ -72,6 +88,10 @@ impl ImportTable for Handler<FuelBlockIdsToHeights, FuelBlockIdsToHeights> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<FuelBlockIdsToHeights, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         for entry in group {
             tx.storage::<Self::TableInSnapshot>()
                 .insert(&entry.key, &entry.value)?;
@@ -90,6 +110,10 @@ impl ImportTable for Handler<OwnedTransactions, OwnedTransactions> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<OwnedTransactions, _, _, _>(tx, group, |entry| {
+                entry.key.clone()
+            })?;
         for entry in group {
             tx.storage::<OwnedTransactions>()
                 .insert(&entry.key, &entry.value)?;
@@ -108,6 +132,10 @@ impl ImportTable for Handler<OwnedMessageIds, Messages> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<OwnedMessageIds, _, _, _>(tx, group, |entry| {
+                OwnedMessageKey::new(entry.value.recipient(), entry.value.nonce())
+            })?;
         let events = group
             .into_iter()
             .map(|TableEntry { value, .. }| Cow::Owned(Event::MessageImported(value)));
@@ -126,6 +154,9 @@ impl ImportTable for Handler<OwnedCoins, Coins> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = self.retain_admitted::<OwnedCoins, _, _, _>(tx, group, |entry| {
+            owner_coin_id_key(entry.value.owner(), &entry.key)
+        })?;
         let events = group.into_iter().map(|TableEntry { value, key }| {
             Cow::Owned(Event::CoinCreated(value.uncompress(key)))
         });
@@ -144,9 +175,7 @@ impl ImportTable for Handler<ContractsInfo, Transactions> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
-        let transactions = group.iter().map(|TableEntry { value, .. }| value);
-        worker_service::process_transactions(transactions, tx)?;
-        Ok(())
+        self.import_contracts_info(group.iter().map(|TableEntry { value, .. }| value), tx)
     }
 }
 
@@ -160,9 +189,7 @@ impl ImportTable for Handler<ContractsInfo, OldTransactions> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
-        let transactions = group.iter().map(|TableEntry { value, .. }| value);
-        worker_service::process_transactions(transactions, tx)?;
-        Ok(())
+        self.import_contracts_info(group.iter().map(|TableEntry { value, .. }| value), tx)
     }
 }
 
@@ -176,6 +203,12 @@ impl ImportTable for Handler<OldFuelBlocks, FuelBlocks> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = group
+            .into_iter()
+            .filter(|TableEntry { key, .. }| self.retains_old_block(key))
+            .collect::<Vec<_>>();
+        let group =
+            self.retain_admitted::<OldFuelBlocks, _, _, _>(tx, group, |entry| entry.key)?;
         let blocks = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -194,6 +227,12 @@ impl ImportTable for Handler<OldFuelBlocks, OldFuelBlocks> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = group
+            .into_iter()
+            .filter(|TableEntry { key, .. }| self.retains_old_block(key))
+            .collect::<Vec<_>>();
+        let group =
+            self.retain_admitted::<OldFuelBlocks, _, _, _>(tx, group, |entry| entry.key)?;
         let blocks = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -212,6 +251,14 @@ impl ImportTable for Handler<OldFuelBlockConsensus, SealedBlockConsensus> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = group
+            .into_iter()
+            .filter(|TableEntry { key, .. }| self.retains_old_block(key))
+            .collect::<Vec<_>>();
+        let group =
+            self.retain_admitted::<OldFuelBlockConsensus, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         let blocks = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -230,6 +277,14 @@ impl ImportTable for Handler<OldFuelBlockConsensus, OldFuelBlockConsensus> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = group
+            .into_iter()
+            .filter(|TableEntry { key, .. }| self.retains_old_block(key))
+            .collect::<Vec<_>>();
+        let group =
+            self.retain_admitted::<OldFuelBlockConsensus, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         let blocks = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -248,6 +303,14 @@ impl ImportTable for Handler<OldTransactions, Transactions> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = group
+            .into_iter()
+            .filter(|TableEntry { key, .. }| self.retains_old_transaction(key))
+            .collect::<Vec<_>>();
+        let group =
+            self.retain_admitted::<OldTransactions, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         let transactions = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -266,6 +329,14 @@ impl ImportTable for Handler<OldTransactions, OldTransactions> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = group
+            .into_iter()
+            .filter(|TableEntry { key, .. }| self.retains_old_transaction(key))
+            .collect::<Vec<_>>();
+        let group =
+            self.retain_admitted::<OldTransactions, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         let transactions = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -284,6 +355,8 @@ impl ImportTable for Handler<SpentMessages, SpentMessages> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<SpentMessages, _, _, _>(tx, group, |entry| entry.key)?;
         for entry in group {
             tx.storage_as_mut::<SpentMessages>()
                 .insert(&entry.key, &entry.value)?;
@@ -302,6 +375,10 @@ impl ImportTable for Handler<FuelBlockIdsToHeights, FuelBlocks> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<FuelBlockIdsToHeights, _, _, _>(tx, group, |entry| {
+                entry.value.id()
+            })?;
         for entry in group {
             tx.storage_as_mut::<FuelBlockIdsToHeights>()
                 .insert(&entry.value.id(), &entry.key)?;
@@ -320,6 +397,10 @@ impl ImportTable for Handler<FuelBlockIdsToHeights, OldFuelBlocks> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<FuelBlockIdsToHeights, _, _, _>(tx, group, |entry| {
+                entry.value.id()
+            })?;
         for entry in group {
             tx.storage_as_mut::<FuelBlockIdsToHeights>()
                 .insert(&entry.value.id(), &entry.key)?;
@@ -327,3 +408,33 @@ impl ImportTable for Handler<FuelBlockIdsToHeights, OldFuelBlocks> {
         Ok(())
     }
 }
+
+impl<TableInSnapshot> Handler<ContractsInfo, TableInSnapshot> {
+    /// Records the salt of the contracts deployed by the `transactions`.
+    fn import_contracts_info<'a>(
+        &self,
+        transactions: impl Iterator<Item = &'a Transaction>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<OffChain>>,
+    ) -> anyhow::Result<()> {
+        let contracts = transactions
+            .filter_map(|transaction| match transaction {
+                Transaction::Create(create) => Some(create),
+                _ => None,
+            })
+            .map(|create| {
+                let contract_id = worker_service::created_contract_id(create)?;
+                Ok((contract_id, *create.salt()))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let contracts = self.retain_admitted::<ContractsInfo, _, _, _>(
+            tx,
+            contracts,
+            |(contract_id, _)| *contract_id,
+        )?;
+        for (contract_id, salt) in contracts {
+            tx.storage::<ContractsInfo>()
+                .insert(&contract_id, &salt.into())?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use super::{
     import_task::ImportTable,
+    ConflictPolicy,
     Handler,
 };
 use crate::database::{
@@ -26,6 +27,7 @@ use fuel_core_storage::{
     },
     transactional::StorageTransaction,
     StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_types::{
     self,
@@ -48,7 +50,7 @@ impl ImportTable for Handler<Coins, Coins> {
         tx: &mut StorageTransaction<&mut GenesisDatabase>,
     ) -> anyhow::Result<()> {
         group.into_iter().try_for_each(|coin| {
-            init_coin(tx, &coin, self.block_height)?;
+            init_coin(tx, &coin, self.block_height, self.on_conflict)?;
             Ok(())
         })
     }
@@ -64,9 +66,9 @@ impl ImportTable for Handler<Messages, Messages> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase>,
     ) -> anyhow::Result<()> {
-        group
-            .into_iter()
-            .try_for_each(|message| init_da_message(tx, message, self.da_block_height))
+        group.into_iter().try_for_each(|message| {
+            init_da_message(tx, message, self.da_block_height, self.on_conflict)
+        })
     }
 }
 
@@ -80,6 +82,10 @@ impl ImportTable for Handler<ProcessedTransactions, ProcessedTransactions> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<ProcessedTransactions, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         group.into_iter().try_for_each(|transaction| {
             tx.storage_as_mut::<ProcessedTransactions>()
                 .insert(&transaction.key, &transaction.value)
@@ -100,7 +106,7 @@ impl ImportTable for Handler<ContractsRawCode, ContractsRawCode> {
         tx: &mut StorageTransaction<&mut GenesisDatabase>,
     ) -> anyhow::Result<()> {
         group.into_iter().try_for_each(|contract| {
-            init_contract_raw_code(tx, &contract)?;
+            init_contract_raw_code(tx, &contract, self.on_conflict)?;
             Ok::<(), anyhow::Error>(())
         })
     }
@@ -117,7 +123,12 @@ impl ImportTable for Handler<ContractsLatestUtxo, ContractsLatestUtxo> {
         tx: &mut StorageTransaction<&mut GenesisDatabase>,
     ) -> anyhow::Result<()> {
         group.into_iter().try_for_each(|contract| {
-            init_contract_latest_utxo(tx, &contract, self.block_height)?;
+            init_contract_latest_utxo(
+                tx,
+                &contract,
+                self.block_height,
+                self.on_conflict,
+            )?;
             Ok::<(), anyhow::Error>(())
         })
     }
@@ -133,6 +144,10 @@ impl ImportTable for Handler<ContractsState, ContractsState> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<ContractsState, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         tx.update_contract_states(group)?;
         Ok(())
     }
@@ -148,6 +163,10 @@ impl ImportTable for Handler<ContractsAssets, ContractsAssets> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<ContractsAssets, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         tx.update_contract_balances(group)?;
         Ok(())
     }
@@ -163,6 +182,10 @@ impl ImportTable for Handler<FuelBlockMerkleData, FuelBlockMerkleData> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group =
+            self.retain_admitted::<FuelBlockMerkleData, _, _, _>(tx, group, |entry| {
+                entry.key
+            })?;
         let blocks = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -183,6 +206,11 @@ impl ImportTable for Handler<FuelBlockMerkleMetadata, FuelBlockMerkleMetadata> {
         group: Vec<TableEntry<Self::TableInSnapshot>>,
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
+        let group = self.retain_admitted::<FuelBlockMerkleMetadata, _, _, _>(
+            tx,
+            group,
+            |entry| entry.key.clone(),
+        )?;
         let blocks = group
             .iter()
             .map(|TableEntry { key, value, .. }| (key, value));
@@ -198,6 +226,7 @@ fn init_coin(
     transaction: &mut StorageTransaction<&mut GenesisDatabase>,
     coin: &TableEntry<Coins>,
     height: BlockHeight,
+    on_conflict: ConflictPolicy,
 ) -> anyhow::Result<()> {
     let utxo_id = coin.key;
 
@@ -218,10 +247,19 @@ fn init_coin(
         ));
     }
 
+    if on_conflict == ConflictPolicy::Skip
+        && transaction
+            .storage_as_ref::<Coins>()
+            .contains_key(&utxo_id)?
+    {
+        return Ok(());
+    }
+
     if transaction
         .storage::<Coins>()
         .insert(&utxo_id, &compressed_coin)?
         .is_some()
+        && on_conflict == ConflictPolicy::Error
     {
         return Err(anyhow!("Coin should not exist"));
     }
@@ -233,6 +271,7 @@ fn init_contract_latest_utxo(
     transaction: &mut StorageTransaction<&mut GenesisDatabase>,
     entry: &TableEntry<ContractsLatestUtxo>,
     height: BlockHeight,
+    on_conflict: ConflictPolicy,
 ) -> anyhow::Result<()> {
     let contract_id = entry.key;
 
@@ -242,10 +281,19 @@ fn init_contract_latest_utxo(
         ));
     }
 
+    if on_conflict == ConflictPolicy::Skip
+        && transaction
+            .storage_as_ref::<ContractsLatestUtxo>()
+            .contains_key(&contract_id)?
+    {
+        return Ok(());
+    }
+
     if transaction
         .storage::<ContractsLatestUtxo>()
         .insert(&contract_id, &entry.value)?
         .is_some()
+        && on_conflict == ConflictPolicy::Error
     {
         return Err(anyhow!("Contract utxo should not exist"));
     }
@@ -256,15 +304,25 @@ fn init_contract_latest_utxo(
 fn init_contract_raw_code(
     transaction: &mut StorageTransaction<&mut GenesisDatabase>,
     entry: &TableEntry<ContractsRawCode>,
+    on_conflict: ConflictPolicy,
 ) -> anyhow::Result<()> {
     let contract = entry.value.as_ref();
     let contract_id = entry.key;
 
+    if on_conflict == ConflictPolicy::Skip
+        && transaction
+            .storage_as_ref::<ContractsRawCode>()
+            .contains_key(&contract_id)?
+    {
+        return Ok(());
+    }
+
     // insert contract code
     if transaction
         .storage::<ContractsRawCode>()
         .insert(&contract_id, contract)?
         .is_some()
+        && on_conflict == ConflictPolicy::Error
     {
         return Err(anyhow!("Contract code should not exist"));
     }
@@ -276,6 +334,7 @@ fn init_da_message(
     transaction: &mut StorageTransaction<&mut GenesisDatabase>,
     msg: TableEntry<Messages>,
     da_height: DaBlockHeight,
+    on_conflict: ConflictPolicy,
 ) -> anyhow::Result<()> {
     let message: Message = msg.value;
 
@@ -285,10 +344,19 @@ fn init_da_message(
         ));
     }
 
+    if on_conflict == ConflictPolicy::Skip
+        && transaction
+            .storage_as_ref::<Messages>()
+            .contains_key(message.id())?
+    {
+        return Ok(());
+    }
+
     if transaction
         .storage::<Messages>()
         .insert(message.id(), &message)?
         .is_some()
+        && on_conflict == ConflictPolicy::Error
     {
         return Err(anyhow!("Message should not exist"));
     }
@@ -0,0 +1,66 @@
+use std::sync::mpsc::{
+    sync_channel,
+    IntoIter,
+    Receiver,
+};
+
+/// Reads the items of `inner` on a separate thread, up to `capacity` items ahead of the
+/// consumer. Reading the next group of a snapshot then overlaps with applying the current
+/// one, so a disk-bound import takes about as long as the slower of both instead of
+/// their sum.
+///
+/// The reader thread is only started once the groups are iterated, and stops as soon as
+/// the iterator is dropped.
+pub struct Prefetch<I> {
+    inner: I,
+    capacity: usize,
+}
+
+impl<I> Prefetch<I> {
+    pub fn new(inner: I, capacity: usize) -> Self {
+        Self { inner, capacity }
+    }
+}
+
+impl<I> IntoIterator for Prefetch<I>
+where
+    I: IntoIterator + Send + 'static,
+    I::Item: Send + 'static,
+{
+    type Item = I::Item;
+    type IntoIter = IntoIter<I::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (sender, receiver): (_, Receiver<I::Item>) = sync_channel(self.capacity);
+        let inner = self.inner;
+        std::thread::spawn(move || {
+            for item in inner {
+                // The consumer stopped, e.g. because the import was cancelled
+                if sender.send(item).is_err() {
+                    break;
+                }
+            }
+        });
+        receiver.into_iter()
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefetch__yields_all_items_in_order() {
+        // given
+        let items = (0..100).collect::<Vec<_>>();
+
+        // when
+        let prefetched = Prefetch::new(items.clone(), 2)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // then
+        assert_eq!(prefetched, items);
+    }
+}
@@ -1,6 +1,10 @@
 use std::{
     borrow::Cow,
     io::IsTerminal,
+    sync::{
+        Arc,
+        Mutex,
+    },
 };
 
 use indicatif::{
@@ -19,6 +23,7 @@ use tracing::{
 pub struct ProgressReporter {
     bar: ProgressBar,
     target: ReportMethod,
+    aggregate: Option<AggregateReporter>,
 }
 
 impl Default for ProgressReporter {
@@ -49,7 +54,11 @@ impl ProgressReporter {
             bar.set_style(Self::style(max.is_some()));
         }
 
-        ProgressReporter { bar, target }
+        ProgressReporter {
+            bar,
+            target,
+            aggregate: None,
+        }
     }
 
     fn style(length_known: bool) -> ProgressStyle {
@@ -66,7 +75,11 @@ impl ProgressReporter {
     pub fn set_index(&self, index: usize) {
         // So that the last element shows up as, e.g., 100/100 and not 99/100.
         let display_index = u64::try_from(index).unwrap_or(u64::MAX).saturating_add(1);
+        let previous_index = self.bar.position();
         self.bar.set_position(display_index);
+        if let Some(aggregate) = &self.aggregate {
+            aggregate.advance(display_index.saturating_sub(previous_index));
+        }
         if let ReportMethod::Logs(span) = &self.target {
             span.in_scope(|| {
                 if let Some(len) = self.bar.length() {
@@ -80,9 +93,62 @@ impl ProgressReporter {
     }
 }
 
+/// Sums up the progress of all the reporters registered with a [`MultipleProgressReporter`]
+/// and reports the overall percentage and ETA at the level of its span.
+#[derive(Clone)]
+pub struct AggregateReporter {
+    bar: ProgressBar,
+    span: Span,
+}
+
+impl AggregateReporter {
+    fn new(span: Span) -> Self {
+        let bar = ProgressBar::with_draw_target(Some(0), ProgressDrawTarget::hidden());
+        let style = ProgressStyle::with_template(
+            "[{elapsed_precise}] {bar:.64.on_black} {percent:>3}% overall {eta}",
+        )
+        .expect("hard coded templates to be valid");
+        bar.set_style(style);
+        Self { bar, span }
+    }
+
+    /// The number of groups handled across all reporters.
+    pub fn position(&self) -> u64 {
+        self.bar.position()
+    }
+
+    /// The number of groups to handle across all reporters.
+    pub fn length(&self) -> u64 {
+        self.bar.length().unwrap_or_default()
+    }
+
+    /// The overall progress, in percent.
+    pub fn percent(&self) -> u64 {
+        self.position()
+            .saturating_mul(100)
+            .checked_div(self.length())
+            .unwrap_or(100)
+    }
+
+    fn add_groups(&self, num_groups: u64) {
+        self.bar.inc_length(num_groups);
+    }
+
+    fn advance(&self, handled_groups: u64) {
+        self.bar.inc(handled_groups);
+        if !MultipleProgressReporter::should_display_bars() {
+            self.span.in_scope(|| {
+                let human_eta = HumanDuration(self.bar.eta());
+                tracing::info!("Overall: {}%. ({human_eta})", self.percent());
+            })
+        }
+    }
+}
+
 pub struct MultipleProgressReporter {
     multi_progress: MultiProgress,
     span: Span,
+    aggregate: AggregateReporter,
 }
 
 impl MultipleProgressReporter {
@@ -116,13 +182,30 @@ impl MultipleProgressReporter {
             ReportMethod::Logs(span)
         };
 
+        if let Some(num_groups) = num_groups {
+            self.aggregate
+                .add_groups(u64::try_from(num_groups).unwrap_or(u64::MAX));
+        }
         self.register(ProgressReporter::new(target, num_groups))
     }
 
+    /// Returns the reporter summing up the progress of all the table reporters.
+    #[cfg(test)]
+    pub fn aggregate(&self) -> &AggregateReporter {
+        &self.aggregate
+    }
+
     fn new_target(target: ProgressDrawTarget, span: Span) -> Self {
+        let multi_progress = MultiProgress::with_draw_target(target);
+        let aggregate = AggregateReporter::new(span.clone());
+        let aggregate = AggregateReporter {
+            bar: multi_progress.add(aggregate.bar),
+            span: aggregate.span,
+        };
         Self {
-            multi_progress: MultiProgress::with_draw_target(target),
+            multi_progress,
             span,
+            aggregate,
         }
     }
 
@@ -131,6 +214,103 @@ impl MultipleProgressReporter {
         ProgressReporter {
             bar,
             target: reporter.target,
+            aggregate: Some(self.aggregate.clone()),
         }
     }
 }
+
+/// The progress of the snapshot import performed at genesis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportStatus {
+    /// Whether the import is currently running.
+    pub in_progress: bool,
+    /// The number of tables that finished importing.
+    pub tables_done: usize,
+    /// The number of tables scheduled for import so far.
+    ///
+    /// The tables are scheduled one after another and the small ones are imported
+    /// before the next one is scheduled, so `tables_done` may catch up with
+    /// `tables_total` while the import is still running. Use `in_progress` to tell
+    /// whether the import finished.
+    pub tables_total: usize,
+    /// The number of rows applied by the finished tables.
+    pub rows_applied: u64,
+}
+
+/// Shared handle to the [`ImportStatus`], updated by the importer and read by the node.
+#[derive(Debug, Default, Clone)]
+pub struct ImportStatusTracker(Arc<Mutex<ImportStatus>>);
+
+impl ImportStatusTracker {
+    /// Returns the current status of the import.
+    pub fn status(&self) -> ImportStatus {
+        *self.0.lock().expect("poisoned")
+    }
+
+    pub(crate) fn start(&self) {
+        self.update(|status| {
+            *status = ImportStatus {
+                in_progress: true,
+                ..Default::default()
+            }
+        });
+    }
+
+    pub(crate) fn table_scheduled(&self) {
+        self.update(|status| status.tables_total = status.tables_total.saturating_add(1));
+    }
+
+    pub(crate) fn table_done(&self, rows: u64) {
+        self.update(|status| {
+            status.tables_done = status.tables_done.saturating_add(1);
+            status.rows_applied = status.rows_applied.saturating_add(rows);
+        });
+    }
+
+    pub(crate) fn finish(&self) {
+        self.update(|status| status.in_progress = false);
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ImportStatus)) {
+        f(&mut self.0.lock().expect("poisoned"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregate_reflects_combined_progress_of_reporters() {
+        // given
+        let multi_progress = MultipleProgressReporter::new(tracing::info_span!("test"));
+        let first = multi_progress.table_reporter(Some(4), "first");
+        let second = multi_progress.table_reporter(Some(6), "second");
+
+        // when
+        first.set_index(1);
+        second.set_index(2);
+
+        // then
+        let aggregate = multi_progress.aggregate();
+        assert_eq!(aggregate.length(), 10);
+        assert_eq!(aggregate.position(), 5);
+        assert_eq!(aggregate.percent(), 50);
+    }
+
+    #[test]
+    fn aggregate_is_complete_once_all_reporters_finish() {
+        // given
+        let multi_progress = MultipleProgressReporter::new(tracing::info_span!("test"));
+        let first = multi_progress.table_reporter(Some(2), "first");
+        let second = multi_progress.table_reporter(Some(3), "second");
+
+        // when
+        first.set_index(0);
+        first.set_index(1);
+        second.set_index(2);
+
+        // then
+        assert_eq!(multi_progress.aggregate().percent(), 100);
+    }
+}
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 use fuel_core_services::StateWatcher;
 use futures::{
@@ -6,11 +9,15 @@ use futures::{
     TryStreamExt,
 };
 use itertools::Itertools;
-use tokio::task::JoinSet;
+use tokio::{
+    sync::Semaphore,
+    task::JoinSet,
+};
 
 pub struct TaskManager<T> {
     set: JoinSet<anyhow::Result<T>>,
     cancel_token: CancellationToken,
+    permits: Option<Arc<Semaphore>>,
 }
 
 #[async_trait::async_trait]
@@ -79,9 +86,17 @@ impl<T> TaskManager<T> {
         Self {
             set: JoinSet::new(),
             cancel_token: CancellationToken::new(outside_cancel),
+            permits: None,
         }
     }
 
+    /// Limits the number of blocking tasks running at the same time to `max`, the
+    /// others wait for a running task to finish. Unlimited if `None`.
+    pub fn with_max_concurrency(mut self, max: Option<usize>) -> Self {
+        self.permits = max.map(|max| Arc::new(Semaphore::new(max.max(1))));
+        self
+    }
+
     pub fn run<F>(&mut self, arg: F) -> anyhow::Result<T>
     where
         F: FnOnce(CancellationToken) -> anyhow::Result<T>,
@@ -109,7 +124,14 @@ where
         F: FnOnce(CancellationToken) -> anyhow::Result<T> + Send + 'static,
     {
         let token = self.cancel_token.clone();
-        self.set.spawn_blocking(move || arg(token));
+        let permits = self.permits.clone();
+        let handle = tokio::runtime::Handle::current();
+        self.set.spawn_blocking(move || {
+            let _permit = permits
+                .map(|permits| handle.block_on(permits.acquire_owned()))
+                .transpose()?;
+            arg(token)
+        });
     }
 
     pub async fn wait(self) -> anyhow::Result<Vec<T>> {
@@ -124,11 +146,32 @@ where
 
         results.into_iter().try_collect()
     }
+
+    /// Like [`TaskManager::wait`], but cancels the tasks and returns `None` if they don't
+    /// finish within `timeout`.
+    pub async fn wait_for(self, timeout: Duration) -> Option<anyhow::Result<Vec<T>>> {
+        let cancel_token = self.cancel_token.clone();
+        let results = tokio::time::timeout(timeout, self.wait()).await.ok();
+        if results.is_none() {
+            cancel_token.cancel();
+        }
+        results
+    }
 }
 
+#[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
-    use std::time::Duration;
+    use std::{
+        sync::{
+            atomic::{
+                AtomicUsize,
+                Ordering,
+            },
+            Arc,
+        },
+        time::Duration,
+    };
 
     use anyhow::bail;
     use tokio_util::sync::CancellationToken as TokioCancelToken;
@@ -188,6 +231,58 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn wait_for__cancels_tasks_that_exceed_the_timeout() {
+        // given
+        let mut workers = TaskManager::new(TokioCancelToken::new());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        workers.spawn_blocking(move |token| {
+            while !token.is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            tx.send(()).unwrap();
+            Ok(())
+        });
+
+        // when
+        let result = workers.wait_for(Duration::from_millis(10)).await;
+
+        // then
+        assert!(result.is_none());
+        tokio::time::timeout(Duration::from_secs(2), rx)
+            .await
+            .expect("Cancellation should have been signaled")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn with_max_concurrency__limits_the_running_tasks() {
+        // given
+        let mut workers =
+            TaskManager::new(TokioCancelToken::new()).with_max_concurrency(Some(2));
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        // when
+        for _ in 0..8 {
+            let running = running.clone();
+            let max_running = max_running.clone();
+            workers.spawn_blocking(move |_| {
+                let now_running =
+                    running.fetch_add(1, Ordering::SeqCst).saturating_add(1);
+                max_running.fetch_max(now_running, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(20));
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            });
+        }
+        let results = workers.wait().await.unwrap();
+
+        // then
+        assert_eq!(results.len(), 8);
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+
     #[tokio::test]
     async fn stops_on_cancellation() {
         // given
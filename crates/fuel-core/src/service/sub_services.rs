@@ -172,7 +172,7 @@ pub fn init_sub_services(
         fuel_core_gas_price_service::new_service(last_height, update_algo)?;
     let next_algo = gas_price_service.shared.clone();
 
-    let gas_price_provider = FuelGasPriceProvider::new(next_algo);
+    let gas_price_provider = FuelGasPriceProvider::new(next_algo.clone());
     let txpool = fuel_core_txpool::new_service(
         config.txpool.clone(),
         database.on_chain().clone(),
@@ -183,7 +183,8 @@ pub fn init_sub_services(
         consensus_parameters_provider.clone(),
         SharedMemoryPool::new(config.memory_pool_size),
     );
-    let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
+    let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone())
+        .with_block_gas_limit(config.test_block_gas_limit);
 
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
@@ -276,6 +277,8 @@ pub fn init_sub_services(
         database,
         block_importer: importer_adapter,
         executor,
+        gas_price_algo: next_algo,
+        import_status: Default::default(),
         config: config.clone(),
     };
 
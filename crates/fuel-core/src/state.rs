@@ -25,6 +25,7 @@ use fuel_core_storage::{
 };
 use std::fmt::Debug;
 
+pub mod contract_metrics;
 pub mod data_source;
 pub mod generic_database;
 pub mod in_memory;
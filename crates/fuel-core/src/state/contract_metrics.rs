@@ -0,0 +1,175 @@
+use crate::state::{
+    iterable_key_value_view::IterableKeyValueViewWrapper,
+    IterableKeyValueView,
+    TransactableStorage,
+};
+use fuel_core_metrics::core_metrics::{
+    database_metrics,
+    ColumnOperation,
+};
+use fuel_core_storage::{
+    column::Column,
+    iter::{
+        BoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    transactional::Changes,
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::sync::Arc;
+
+/// The storage wrapper that counts the operations on the contract columns
+/// in the database metrics.
+///
+/// The operations are counted at the key-value level, so reads of the VM and
+/// writes committed in a batch by a transaction are counted as well.
+#[derive(Debug)]
+pub struct ContractMetrics<S: ?Sized>(Arc<S>);
+
+impl<S: ?Sized> ContractMetrics<S> {
+    pub fn new(storage: Arc<S>) -> Self {
+        Self(storage)
+    }
+}
+
+const CONTRACT_COLUMNS: [Column; 3] = [
+    Column::ContractsRawCode,
+    Column::ContractsState,
+    Column::ContractsAssets,
+];
+
+fn is_contract_column(column: Column) -> bool {
+    matches!(
+        column,
+        Column::ContractsRawCode | Column::ContractsState | Column::ContractsAssets
+    )
+}
+
+fn record_get(column: Column) {
+    if is_contract_column(column) {
+        database_metrics().record_column_operation(column.name(), ColumnOperation::Get);
+    }
+}
+
+/// Returns the number of inserts and removes of each contract column in the `changes`.
+fn contract_writes(changes: &Changes) -> Vec<(Column, u64, u64)> {
+    CONTRACT_COLUMNS
+        .into_iter()
+        .filter_map(|column| {
+            let operations = changes.get(&column.as_u32())?;
+            let (mut inserts, mut removes) = (0u64, 0u64);
+            for operation in operations.values() {
+                match operation {
+                    WriteOperation::Insert(_) => inserts = inserts.saturating_add(1),
+                    WriteOperation::Remove => removes = removes.saturating_add(1),
+                }
+            }
+            Some((column, inserts, removes))
+        })
+        .collect()
+}
+
+impl<S> KeyValueInspect for ContractMetrics<S>
+where
+    S: KeyValueInspect<Column = Column> + ?Sized,
+{
+    type Column = Column;
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        record_get(column);
+        self.0.exists(key, column)
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        record_get(column);
+        self.0.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        record_get(column);
+        self.0.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        record_get(column);
+        self.0.read(key, column, buf)
+    }
+}
+
+impl<S> IterableStore for ContractMetrics<S>
+where
+    S: IterableStore<Column = Column> + ?Sized,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.0.iter_store(column, prefix, start, direction)
+    }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.0.iter_store_keys(column, prefix, start, direction)
+    }
+}
+
+impl TransactableStorage<BlockHeight>
+    for ContractMetrics<dyn TransactableStorage<BlockHeight, Column = Column>>
+{
+    fn commit_changes(
+        &self,
+        height: Option<BlockHeight>,
+        changes: Changes,
+    ) -> StorageResult<()> {
+        let writes = contract_writes(&changes);
+        self.0.commit_changes(height, changes)?;
+        let metrics = database_metrics();
+        for (column, inserts, removes) in writes {
+            metrics.record_column_operations(
+                column.name(),
+                ColumnOperation::Insert,
+                inserts,
+            );
+            metrics.record_column_operations(
+                column.name(),
+                ColumnOperation::Remove,
+                removes,
+            );
+        }
+        Ok(())
+    }
+
+    fn latest_view(&self) -> StorageResult<IterableKeyValueView<Self::Column>> {
+        let view = self.0.latest_view()?;
+        Ok(IterableKeyValueView::from_storage(
+            IterableKeyValueViewWrapper::new(ContractMetrics::new(Arc::new(view))),
+        ))
+    }
+}
@@ -10,6 +10,7 @@ use fuel_core_storage::{
     },
     kv_store::{
         KVItem,
+        KeyItem,
         KeyValueInspect,
         Value,
     },
@@ -86,4 +87,14 @@ where
     ) -> BoxedIter<KVItem> {
         self.data.iter_store(column, prefix, start, direction)
     }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.data.iter_store_keys(column, prefix, start, direction)
+    }
 }
@@ -6,6 +6,7 @@ use fuel_core_storage::{
     },
     kv_store::{
         KVItem,
+        KeyItem,
         KeyValueInspect,
         Value,
     },
@@ -43,6 +44,22 @@ impl<Storage> GenericDatabase<Storage> {
     }
 }
 
+impl<Storage> GenericDatabase<Storage>
+where
+    Storage: IterableStore,
+{
+    /// Iterates over the keys of the `column` from the `start` key in the given
+    /// `direction`. The values are neither decoded nor, when the store supports it, read.
+    pub fn iter_keys_only(
+        &self,
+        column: Storage::Column,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = StorageResult<Vec<u8>>> + '_ {
+        self.iter_store_keys(column, None, start, direction)
+    }
+}
+
 impl<M, Storage> StorageInspect<M> for GenericDatabase<Storage>
 where
     M: Mappable,
@@ -138,6 +155,17 @@ where
     ) -> BoxedIter<KVItem> {
         self.storage.iter_store(column, prefix, start, direction)
     }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.storage
+            .iter_store_keys(column, prefix, start, direction)
+    }
 }
 
 impl<Storage> AsRef<Storage> for GenericDatabase<Storage> {
@@ -20,6 +20,7 @@ use fuel_core_storage::{
     },
     kv_store::{
         KVItem,
+        KeyItem,
         KeyValueInspect,
         StorageColumn,
         Value,
@@ -504,6 +505,48 @@ where
             })
     }
 
+    /// Iterates over the keys of the `column` from the `start` key, without reading the
+    /// values. The keys are read through the raw iterator, because the regular one
+    /// copies the value of each entry.
+    fn _iter_keys(
+        &self,
+        column: Description::Column,
+        opts: ReadOptions,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> impl Iterator<Item = KeyItem> + '_ {
+        let mut iter = self.db.raw_iterator_cf_opt(&self.cf(column), opts);
+        match (start, direction) {
+            (None, IterDirection::Forward) => iter.seek_to_first(),
+            (None, IterDirection::Reverse) => iter.seek_to_last(),
+            (Some(start), IterDirection::Forward) => iter.seek(start),
+            (Some(start), IterDirection::Reverse) => iter.seek_for_prev(start),
+        }
+
+        let mut finished = false;
+        iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+            let Some(key) = iter.key().map(|key| key.to_vec()) else {
+                finished = true;
+                return iter
+                    .status()
+                    .err()
+                    .map(|e| Err(DatabaseError::Other(e.into()).into()));
+            };
+
+            database_metrics().read_meter.inc();
+            database_metrics().bytes_read.observe(key.len() as f64);
+
+            match direction {
+                IterDirection::Forward => iter.next(),
+                IterDirection::Reverse => iter.prev(),
+            }
+            Some(Ok(key))
+        })
+    }
+
     pub fn multi_get<K, I>(
         &self,
         column: u32,
@@ -668,6 +711,44 @@ where
             }
         }
     }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        let mut opts = self.read_options();
+        let start = match (prefix, start) {
+            (Some(prefix), None) if direction == IterDirection::Reverse => {
+                // The reverse prefix iteration has to find the key after the prefix first,
+                // so it is left to the regular iteration.
+                return self
+                    .iter_store(column, Some(prefix), None, direction)
+                    .map(|item| item.map(|(key, _)| key))
+                    .into_boxed()
+            }
+            (Some(prefix), None) => {
+                // Setting prefix on the RocksDB level to optimize iteration.
+                opts.set_prefix_same_as_start(true);
+                Some(prefix)
+            }
+            (Some(prefix), Some(start)) if !start.starts_with(prefix) => {
+                return iter::empty().into_boxed()
+            }
+            (_, start) => start,
+        };
+
+        let prefix = prefix.map(|prefix| prefix.to_vec());
+        self._iter_keys(column, opts, start, direction)
+            // Not all tables has a prefix set, so we need to filter out the keys.
+            .take_while(move |item| match (item, &prefix) {
+                (Ok(key), Some(prefix)) => key.starts_with(prefix.as_slice()),
+                _ => true,
+            })
+            .into_boxed()
+    }
 }
 
 impl<Description> RocksDb<Description>
@@ -890,6 +971,43 @@ mod tests {
         assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
     }
 
+    #[test]
+    fn iter_store_keys__yields_the_keys_of_iter_store() {
+        let (mut db, _tmp) = create_db();
+        // Large values, so reading them would dominate the iteration.
+        let value = Arc::new(vec![0xFF; 1024 * 1024]);
+        for prefix in [1u8, 2, 3] {
+            for suffix in [1u8, 2, 3] {
+                db.put(&[prefix, suffix], Column::Metadata, value.clone())
+                    .unwrap();
+            }
+        }
+
+        let queries: [(Option<&[u8]>, Option<&[u8]>); 5] = [
+            (None, None),
+            (Some(&[2]), None),
+            (None, Some(&[2, 2])),
+            (Some(&[2]), Some(&[2, 2])),
+            (Some(&[2]), Some(&[3, 1])),
+        ];
+        for (prefix, start) in queries {
+            for direction in [IterDirection::Forward, IterDirection::Reverse] {
+                let expected: Vec<_> = db
+                    .iter_store(Column::Metadata, prefix, start, direction)
+                    .map_ok(|(key, _)| key)
+                    .try_collect()
+                    .unwrap();
+
+                let keys: Vec<_> = db
+                    .iter_store_keys(Column::Metadata, prefix, start, direction)
+                    .try_collect()
+                    .unwrap();
+
+                assert_eq!(keys, expected, "{prefix:?} {start:?} {direction:?}");
+            }
+        }
+    }
+
     #[test]
     fn can_use_unit_value() {
         let key = vec![0x00];
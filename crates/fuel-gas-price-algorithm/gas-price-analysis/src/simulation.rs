@@ -63,7 +63,17 @@ pub fn run_simulation(
         new_exec_price: 800,
         last_da_gas_price: 200,
         l2_block_height: 0,
-        l2_block_fullness_threshold_percent: 50,
+        l2_block_fullness_threshold: (50, 100),
+        consecutive_low_blocks_required: 1,
+        consecutive_low_blocks: 0,
+        last_update_clamped_to_min: false,
+        surge_threshold_percent: 0,
+        surge_multiplier_percent: 100,
+        floor_decay_percent: 0,
+        floor_decay_after_blocks: 0,
+        min_floor: 0,
+        consecutive_clamped_blocks: 0,
+        strict_overflow: false,
         exec_gas_price_change_percent: 2,
         max_da_gas_price_change_percent: 10,
         total_da_rewards: 0,
@@ -5,6 +5,7 @@
 use std::cmp::{
     max,
     min,
+    Ordering,
 };
 
 #[cfg(test)]
@@ -18,6 +19,10 @@ pub enum Error {
     SkippedDABlock { expected: u32, got: u32 },
     #[error("Could not calculate cost per byte: {bytes:?} bytes, {cost:?} cost")]
     CouldNotCalculateCostPerByte { bytes: u64, cost: u64 },
+    #[error("Invalid algorithm parameters: {0}")]
+    InvalidParameters(String),
+    #[error("The exec gas price {price} overflows when changed by {change_percent}%")]
+    PriceOverflow { price: u64, change_percent: u64 },
 }
 
 /// An algorithm for calculating the gas price for the next block
@@ -157,8 +162,36 @@ pub struct AlgorithmUpdaterV1 {
     /// The height of the next L2 block
     pub l2_block_height: u32,
     /// The threshold of gas usage above and below which the gas price will increase or decrease
-    /// This is a percentage of the total capacity of the L2 block
-    pub l2_block_fullness_threshold_percent: u64,
+    /// This is a `(numerator, denominator)` ratio of the total capacity of the L2 block
+    pub l2_block_fullness_threshold: (u64, u64),
+    /// The number of consecutive L2 blocks below the fullness threshold required before the
+    /// execution gas price decreases. A value of `1` decreases it on every such block
+    pub consecutive_low_blocks_required: u64,
+    /// The number of consecutive L2 blocks below the fullness threshold since the last decrease
+    pub consecutive_low_blocks: u64,
+    /// Whether the last L2 block update would have taken the execution gas price below
+    /// `min_exec_gas_price`, so that the price was held at the minimum
+    pub last_update_clamped_to_min: bool,
+    /// The percentage of the L2 block capacity above which the execution gas price surges
+    /// instead of increasing by `exec_gas_price_change_percent`. A value of `0` disables the surge
+    pub surge_threshold_percent: u64,
+    /// The percentage the execution gas price is multiplied by when it surges, e.g. `150`
+    /// raises it by half
+    pub surge_multiplier_percent: u64,
+    /// The percentage `min_exec_gas_price` decreases by after `floor_decay_after_blocks`
+    /// consecutive L2 blocks clamped to it. A value of `0` disables the decay
+    pub floor_decay_percent: u64,
+    /// The number of consecutive L2 blocks clamped to `min_exec_gas_price` required before
+    /// the floor decays
+    pub floor_decay_after_blocks: u64,
+    /// The lowest `min_exec_gas_price` can decay to
+    pub min_floor: u64,
+    /// The number of consecutive L2 blocks clamped to `min_exec_gas_price` since the floor
+    /// last decayed
+    pub consecutive_clamped_blocks: u64,
+    /// Whether an L2 block update fails with [`Error::PriceOverflow`] instead of saturating
+    /// the exec gas price when the change doesn't fit into a `u64`
+    pub strict_overflow: bool,
     // DA
     /// The lowest the algorithm allows the da gas price to go
     pub min_da_gas_price: u64,
@@ -187,6 +220,57 @@ pub struct AlgorithmUpdaterV1 {
     pub unrecorded_blocks: Vec<BlockBytes>,
 }
 
+/// The configuration and starting prices of a new [`AlgorithmUpdaterV1`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct V1Params {
+    /// The gas price to cover the execution of the first block
+    pub new_exec_price: u64,
+    /// The gas price for the DA portion of the last block
+    pub last_da_gas_price: u64,
+    /// The lowest the algorithm allows the exec gas price to go
+    pub min_exec_gas_price: u64,
+    /// The Percentage the execution gas price will change in a single block
+    pub exec_gas_price_change_percent: u64,
+    /// The height of the next L2 block
+    pub l2_block_height: u32,
+    /// The `(numerator, denominator)` ratio of the block capacity around which the
+    /// execution gas price changes
+    pub l2_block_fullness_threshold: (u64, u64),
+    /// The number of consecutive L2 blocks below the fullness threshold required before the
+    /// execution gas price decreases
+    pub consecutive_low_blocks_required: u64,
+    /// The percentage of the L2 block capacity above which the execution gas price surges.
+    /// A value of `0` disables the surge
+    pub surge_threshold_percent: u64,
+    /// The percentage the execution gas price is multiplied by when it surges
+    pub surge_multiplier_percent: u64,
+    /// The percentage the exec gas price floor decreases by during sustained low usage.
+    /// A value of `0` disables the decay
+    pub floor_decay_percent: u64,
+    /// The number of consecutive L2 blocks clamped to the floor required before it decays
+    pub floor_decay_after_blocks: u64,
+    /// The lowest the exec gas price floor can decay to
+    pub min_floor: u64,
+    /// Whether an L2 block update fails instead of saturating the exec gas price on overflow
+    pub strict_overflow: bool,
+    /// The lowest the algorithm allows the da gas price to go
+    pub min_da_gas_price: u64,
+    /// The maximum percentage that the DA portion of the gas price can change in a single block
+    pub max_da_gas_price_change_percent: u8,
+    /// The P component of the PID control for the DA gas price
+    pub da_p_component: i64,
+    /// The D component of the PID control for the DA gas price
+    pub da_d_component: i64,
+    /// The number of blocks to consider when calculating the average profit
+    pub avg_window: u32,
+}
+
+/// Converts a whole-percent fullness threshold into the `(numerator, denominator)` form used by
+/// [`AlgorithmUpdaterV1::l2_block_fullness_threshold`].
+pub fn fullness_threshold_from_percent(percent: u64) -> (u64, u64) {
+    (percent, 100)
+}
+
 #[derive(Debug, Clone)]
 pub struct RecordedBlock {
     pub height: u32,
@@ -201,6 +285,85 @@ pub struct BlockBytes {
 }
 
 impl AlgorithmUpdaterV1 {
+    /// Creates an updater for a chain without any DA progress, rejecting parameters the
+    /// algorithm can't work with.
+    pub fn new(params: V1Params) -> Result<Self, Error> {
+        let (numerator, denominator) = params.l2_block_fullness_threshold;
+        if denominator == 0 || numerator > denominator {
+            return Err(Error::InvalidParameters(format!(
+                "fullness threshold {numerator}/{denominator} is not within 0% and 100%"
+            )))
+        }
+        if params.exec_gas_price_change_percent > 100 {
+            return Err(Error::InvalidParameters(format!(
+                "exec gas price change of {}% is above 100%",
+                params.exec_gas_price_change_percent
+            )))
+        }
+        if params.surge_threshold_percent > 100 {
+            return Err(Error::InvalidParameters(format!(
+                "surge threshold of {}% is above 100%",
+                params.surge_threshold_percent
+            )))
+        }
+        if params.surge_threshold_percent != 0 && params.surge_multiplier_percent < 100 {
+            return Err(Error::InvalidParameters(format!(
+                "surge multiplier of {}% would lower the exec gas price",
+                params.surge_multiplier_percent
+            )))
+        }
+        if params.floor_decay_percent > 100 {
+            return Err(Error::InvalidParameters(format!(
+                "floor decay of {}% is above 100%",
+                params.floor_decay_percent
+            )))
+        }
+        if params.min_floor > params.min_exec_gas_price {
+            return Err(Error::InvalidParameters(format!(
+                "floor of {} is above the minimum exec gas price of {}",
+                params.min_floor, params.min_exec_gas_price
+            )))
+        }
+        if params.new_exec_price < params.min_exec_gas_price {
+            return Err(Error::InvalidParameters(format!(
+                "exec gas price {} is below the minimum of {}",
+                params.new_exec_price, params.min_exec_gas_price
+            )))
+        }
+
+        let updater = Self {
+            new_exec_price: params.new_exec_price,
+            last_da_gas_price: params.last_da_gas_price,
+            min_exec_gas_price: params.min_exec_gas_price,
+            exec_gas_price_change_percent: params.exec_gas_price_change_percent,
+            l2_block_height: params.l2_block_height,
+            l2_block_fullness_threshold: params.l2_block_fullness_threshold,
+            consecutive_low_blocks_required: params.consecutive_low_blocks_required,
+            consecutive_low_blocks: 0,
+            last_update_clamped_to_min: false,
+            surge_threshold_percent: params.surge_threshold_percent,
+            surge_multiplier_percent: params.surge_multiplier_percent,
+            floor_decay_percent: params.floor_decay_percent,
+            floor_decay_after_blocks: params.floor_decay_after_blocks,
+            min_floor: params.min_floor,
+            consecutive_clamped_blocks: 0,
+            strict_overflow: params.strict_overflow,
+            min_da_gas_price: params.min_da_gas_price,
+            max_da_gas_price_change_percent: params.max_da_gas_price_change_percent,
+            total_da_rewards: 0,
+            da_recorded_block_height: 0,
+            latest_known_total_da_cost: 0,
+            projected_total_da_cost: 0,
+            da_p_component: params.da_p_component,
+            da_d_component: params.da_d_component,
+            profit_avg: 0,
+            avg_window: params.avg_window,
+            latest_da_cost_per_byte: 0,
+            unrecorded_blocks: vec![],
+        };
+        Ok(updater)
+    }
+
     pub fn update_da_record_data(
         &mut self,
         blocks: Vec<RecordedBlock>,
@@ -226,6 +389,9 @@ impl AlgorithmUpdaterV1 {
                 got: height,
             })
         } else {
+            if self.strict_overflow {
+                self.ensure_exec_price_fits(fullness.0, fullness.1)?;
+            }
             self.l2_block_height = height;
             let last_exec_price = self.new_exec_price;
             let last_profit = (self.total_da_rewards as i64)
@@ -236,6 +402,10 @@ impl AlgorithmUpdaterV1 {
             self.projected_total_da_cost = self
                 .projected_total_da_cost
                 .saturating_add(new_projected_da_cost);
+            self.unrecorded_blocks.push(BlockBytes {
+                height,
+                block_bytes,
+            });
             // implicitly deduce what our da gas price was for the l2 block
             self.last_da_gas_price = gas_price.saturating_sub(last_exec_price);
             self.update_exec_gas_price(fullness.0, fullness.1);
@@ -245,6 +415,79 @@ impl AlgorithmUpdaterV1 {
         }
     }
 
+    /// Resets the updater to the state of a fresh chain, starting from `starting_price` (but never
+    /// below `min_exec_gas_price`). The configuration of the algorithm is kept, while the
+    /// accumulated L2 and DA progress is dropped.
+    pub fn reset(&mut self, starting_price: u64) {
+        self.new_exec_price = max(starting_price, self.min_exec_gas_price);
+        self.l2_block_height = 0;
+        self.total_da_rewards = 0;
+        self.da_recorded_block_height = 0;
+        self.latest_known_total_da_cost = 0;
+        self.projected_total_da_cost = 0;
+        self.profit_avg = 0;
+        self.consecutive_low_blocks = 0;
+        self.last_update_clamped_to_min = false;
+        self.consecutive_clamped_blocks = 0;
+        self.unrecorded_blocks.clear();
+    }
+
+    /// Raises `new_exec_price` to at least `min_exec_gas_price`. Updates keep the price above
+    /// the floor, but an updater restored from older metadata may start below it.
+    pub fn normalize(&mut self) {
+        self.new_exec_price = max(self.new_exec_price, self.min_exec_gas_price);
+    }
+
+    /// The number of L2 blocks not yet recorded on the DA layer, whose recording cost is
+    /// only projected.
+    pub fn unrecorded_block_count(&self) -> usize {
+        self.unrecorded_blocks.len()
+    }
+
+    /// The total size of the L2 blocks not yet recorded on the DA layer.
+    pub fn unrecorded_bytes(&self) -> u64 {
+        self.unrecorded_blocks
+            .iter()
+            .fold(0u64, |bytes, block| bytes.saturating_add(block.block_bytes))
+    }
+
+    /// Replays a series of `(used, capacity)` block fullness values through a copy of `config`
+    /// and returns the execution gas price after each step. `config` itself is left untouched,
+    /// so this can be used to tune the execution parameters offline.
+    pub fn simulate(config: &Self, fullness_series: &[(u64, u64)]) -> Vec<u64> {
+        let mut updater = config.clone();
+        fullness_series
+            .iter()
+            .map(|(used, capacity)| {
+                updater.update_exec_gas_price(*used, *capacity);
+                updater.new_exec_price
+            })
+            .collect()
+    }
+
+    /// Returns the execution gas price a block with the given `(used, capacity)` fullness
+    /// would produce, without updating `self` or advancing `l2_block_height`.
+    pub fn preview_l2_block(&self, fullness: (u64, u64)) -> u64 {
+        let mut updater = self.clone();
+        updater.update_exec_gas_price(fullness.0, fullness.1);
+        updater.new_exec_price
+    }
+
+    /// Applies a sequence of `(height, used, capacity)` L2 blocks through
+    /// [`Self::update_l2_block_data`] and returns the execution gas price after each
+    /// block. Each block is charged the current gas price and contributes no bytes to the
+    /// DA cost. Stops at the first error, keeping the blocks applied before it.
+    pub fn run_sequence(&mut self, steps: &[(u32, u64, u64)]) -> Result<Vec<u64>, Error> {
+        steps
+            .iter()
+            .map(|(height, used, capacity)| {
+                let gas_price = self.algorithm().calculate(0);
+                self.update_l2_block_data(*height, (*used, *capacity), 0, gas_price)?;
+                Ok(self.new_exec_price)
+            })
+            .collect()
+    }
+
     fn update_profit_avg(&mut self, new_profit: i64) {
         let old_avg = self.profit_avg;
         let new_avg = old_avg
@@ -255,28 +498,132 @@ impl AlgorithmUpdaterV1 {
         self.profit_avg = new_avg;
     }
 
+    /// Compares the `used / capacity` fullness of a block against the fullness threshold
+    /// without losing precision to rounding.
+    pub fn compare_to_fullness_threshold(&self, used: u64, capacity: u64) -> Ordering {
+        // TODO: Do we want to capture this error? I feel like we should assume capacity isn't 0
+        if capacity == 0 {
+            return Ordering::Equal
+        }
+        let (numerator, denominator) = self.l2_block_fullness_threshold;
+        // The products of two `u64`s always fit into a `u128`
+        let used = u128::from(used).saturating_mul(u128::from(denominator));
+        let threshold = u128::from(numerator).saturating_mul(u128::from(capacity));
+        used.cmp(&threshold)
+    }
+
     fn update_exec_gas_price(&mut self, used: u64, capacity: u64) {
         let mut exec_gas_price = self.new_exec_price;
-        // TODO: Do we want to capture this error? I feel like we should assume capacity isn't 0
-        let fullness_percent = used
-            .saturating_mul(100)
-            .checked_div(capacity)
-            .unwrap_or(self.l2_block_fullness_threshold_percent);
 
-        match fullness_percent.cmp(&self.l2_block_fullness_threshold_percent) {
-            std::cmp::Ordering::Greater => {
+        match self.compare_to_fullness_threshold(used, capacity) {
+            Ordering::Greater if self.is_surge(used, capacity) => {
+                self.consecutive_low_blocks = 0;
+                exec_gas_price = self.surge_price(exec_gas_price);
+            }
+            Ordering::Greater => {
+                self.consecutive_low_blocks = 0;
                 let change_amount = self.change_amount(exec_gas_price);
                 exec_gas_price = exec_gas_price.saturating_add(change_amount);
             }
-            std::cmp::Ordering::Less => {
-                let change_amount = self.change_amount(exec_gas_price);
-                exec_gas_price = exec_gas_price.saturating_sub(change_amount);
+            Ordering::Less => {
+                self.consecutive_low_blocks =
+                    self.consecutive_low_blocks.saturating_add(1);
+                if self.consecutive_low_blocks >= self.consecutive_low_blocks_required {
+                    self.consecutive_low_blocks = 0;
+                    let change_amount = self.change_amount(exec_gas_price);
+                    exec_gas_price = exec_gas_price.saturating_sub(change_amount);
+                }
+            }
+            Ordering::Equal => {
+                self.consecutive_low_blocks = 0;
             }
-            std::cmp::Ordering::Equal => {}
         }
+        self.last_update_clamped_to_min = exec_gas_price < self.min_exec_gas_price;
+        self.update_floor();
         self.new_exec_price = max(self.min_exec_gas_price, exec_gas_price);
     }
 
+    /// Lowers `min_exec_gas_price` by `floor_decay_percent` (but never below `min_floor`)
+    /// once `floor_decay_after_blocks` consecutive updates were clamped to it, so that a
+    /// floor set too high for the demand doesn't hold the price forever.
+    fn update_floor(&mut self) {
+        if !self.last_update_clamped_to_min {
+            self.consecutive_clamped_blocks = 0;
+            return
+        }
+        self.consecutive_clamped_blocks =
+            self.consecutive_clamped_blocks.saturating_add(1);
+        if self.floor_decay_percent == 0
+            || self.consecutive_clamped_blocks < self.floor_decay_after_blocks
+        {
+            return
+        }
+        self.consecutive_clamped_blocks = 0;
+        // Decay by at least one, so that small floors still reach `min_floor`
+        let decay = self
+            .min_exec_gas_price
+            .saturating_mul(self.floor_decay_percent)
+            .saturating_div(100)
+            .max(1);
+        self.min_exec_gas_price = max(
+            self.min_floor,
+            self.min_exec_gas_price.saturating_sub(decay),
+        );
+    }
+
+    fn is_surge(&self, used: u64, capacity: u64) -> bool {
+        if self.surge_threshold_percent == 0 {
+            return false
+        }
+        // The products of two `u64`s always fit into a `u128`
+        let used = u128::from(used).saturating_mul(100);
+        let threshold =
+            u128::from(self.surge_threshold_percent).saturating_mul(u128::from(capacity));
+        used > threshold
+    }
+
+    fn surge_price(&self, principle: u64) -> u64 {
+        let surged = u128::from(principle)
+            .saturating_mul(u128::from(self.surge_multiplier_percent))
+            .saturating_div(100);
+        u64::try_from(surged).unwrap_or(u64::MAX)
+    }
+
+    /// Fails with [`Error::PriceOverflow`] if the update of the exec gas price for a block
+    /// with the `used / capacity` fullness would saturate instead of being exact.
+    fn ensure_exec_price_fits(&self, used: u64, capacity: u64) -> Result<(), Error> {
+        let price = self.new_exec_price;
+        let overflow = match self.compare_to_fullness_threshold(used, capacity) {
+            Ordering::Greater if self.is_surge(used, capacity) => {
+                let surged = u128::from(price)
+                    .saturating_mul(u128::from(self.surge_multiplier_percent))
+                    .saturating_div(100);
+                u64::try_from(surged).is_err()
+            }
+            Ordering::Greater => price
+                .checked_mul(self.exec_gas_price_change_percent)
+                .and_then(|scaled| price.checked_add(scaled.saturating_div(100)))
+                .is_none(),
+            // The price only decreases once enough consecutive low blocks are seen
+            Ordering::Less
+                if self.consecutive_low_blocks.saturating_add(1)
+                    >= self.consecutive_low_blocks_required =>
+            {
+                price
+                    .checked_mul(self.exec_gas_price_change_percent)
+                    .is_none()
+            }
+            Ordering::Less | Ordering::Equal => false,
+        };
+        if overflow {
+            return Err(Error::PriceOverflow {
+                price,
+                change_percent: self.exec_gas_price_change_percent,
+            })
+        }
+        Ok(())
+    }
+
     fn change_amount(&self, principle: u64) -> u64 {
         principle
             .saturating_mul(self.exec_gas_price_change_percent)
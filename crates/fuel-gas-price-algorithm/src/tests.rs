@@ -4,6 +4,16 @@ use super::*;
 #[cfg(test)]
 mod algorithm_v1_tests;
 #[cfg(test)]
+mod new_tests;
+#[cfg(test)]
+mod normalize_tests;
+#[cfg(test)]
+mod reset_tests;
+#[cfg(test)]
+mod run_sequence_tests;
+#[cfg(test)]
+mod simulate_tests;
+#[cfg(test)]
 mod update_da_record_data_tests;
 #[cfg(test)]
 mod update_l2_block_data_tests;
@@ -20,7 +30,14 @@ struct UpdaterBuilder {
     da_d_component: i64,
 
     l2_block_height: u32,
-    l2_block_capacity_threshold: u64,
+    l2_block_fullness_threshold: (u64, u64),
+    consecutive_low_blocks_required: u64,
+    surge_threshold_percent: u64,
+    surge_multiplier_percent: u64,
+    floor_decay_percent: u64,
+    floor_decay_after_blocks: u64,
+    min_floor: u64,
+    strict_overflow: bool,
 
     total_rewards: u64,
     da_recorded_block_height: u32,
@@ -46,7 +63,14 @@ impl UpdaterBuilder {
             da_d_component: 0,
 
             l2_block_height: 0,
-            l2_block_capacity_threshold: 50,
+            l2_block_fullness_threshold: (50, 100),
+            consecutive_low_blocks_required: 1,
+            surge_threshold_percent: 0,
+            surge_multiplier_percent: 100,
+            floor_decay_percent: 0,
+            floor_decay_after_blocks: 0,
+            min_floor: 0,
+            strict_overflow: false,
 
             total_rewards: 0,
             da_recorded_block_height: 0,
@@ -108,7 +132,45 @@ impl UpdaterBuilder {
         mut self,
         l2_block_capacity_threshold: u64,
     ) -> Self {
-        self.l2_block_capacity_threshold = l2_block_capacity_threshold;
+        self.l2_block_fullness_threshold =
+            fullness_threshold_from_percent(l2_block_capacity_threshold);
+        self
+    }
+
+    fn with_l2_block_fullness_threshold(
+        mut self,
+        numerator: u64,
+        denominator: u64,
+    ) -> Self {
+        self.l2_block_fullness_threshold = (numerator, denominator);
+        self
+    }
+
+    fn with_consecutive_low_blocks_required(mut self, required: u64) -> Self {
+        self.consecutive_low_blocks_required = required;
+        self
+    }
+
+    fn with_surge(mut self, threshold_percent: u64, multiplier_percent: u64) -> Self {
+        self.surge_threshold_percent = threshold_percent;
+        self.surge_multiplier_percent = multiplier_percent;
+        self
+    }
+
+    fn with_floor_decay(
+        mut self,
+        decay_percent: u64,
+        after_blocks: u64,
+        min_floor: u64,
+    ) -> Self {
+        self.floor_decay_percent = decay_percent;
+        self.floor_decay_after_blocks = after_blocks;
+        self.min_floor = min_floor;
+        self
+    }
+
+    fn with_strict_overflow(mut self, strict_overflow: bool) -> Self {
+        self.strict_overflow = strict_overflow;
         self
     }
 
@@ -160,7 +222,17 @@ impl UpdaterBuilder {
             da_d_component: self.da_d_component,
 
             l2_block_height: self.l2_block_height,
-            l2_block_fullness_threshold_percent: self.l2_block_capacity_threshold,
+            l2_block_fullness_threshold: self.l2_block_fullness_threshold,
+            consecutive_low_blocks_required: self.consecutive_low_blocks_required,
+            consecutive_low_blocks: 0,
+            last_update_clamped_to_min: false,
+            surge_threshold_percent: self.surge_threshold_percent,
+            surge_multiplier_percent: self.surge_multiplier_percent,
+            floor_decay_percent: self.floor_decay_percent,
+            floor_decay_after_blocks: self.floor_decay_after_blocks,
+            min_floor: self.min_floor,
+            consecutive_clamped_blocks: 0,
+            strict_overflow: self.strict_overflow,
             total_da_rewards: self.total_rewards,
 
             da_recorded_block_height: self.da_recorded_block_height,
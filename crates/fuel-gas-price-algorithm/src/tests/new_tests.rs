@@ -0,0 +1,159 @@
+use super::*;
+
+fn valid_params() -> V1Params {
+    V1Params {
+        new_exec_price: 100,
+        last_da_gas_price: 10,
+        min_exec_gas_price: 50,
+        exec_gas_price_change_percent: 10,
+        l2_block_height: 0,
+        l2_block_fullness_threshold: (50, 100),
+        consecutive_low_blocks_required: 1,
+        surge_threshold_percent: 95,
+        surge_multiplier_percent: 150,
+        floor_decay_percent: 10,
+        floor_decay_after_blocks: 5,
+        min_floor: 20,
+        strict_overflow: false,
+        min_da_gas_price: 0,
+        max_da_gas_price_change_percent: 10,
+        da_p_component: 1,
+        da_d_component: 1,
+        avg_window: 1,
+    }
+}
+
+#[test]
+fn new__accepts_valid_parameters() {
+    // given
+    let params = valid_params();
+
+    // when
+    let updater = AlgorithmUpdaterV1::new(params).unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 100);
+    assert_eq!(updater.min_exec_gas_price, 50);
+    assert_eq!(updater.l2_block_fullness_threshold, (50, 100));
+    assert!(updater.unrecorded_blocks.is_empty());
+}
+
+#[test]
+fn new__rejects_fullness_threshold_above_100_percent() {
+    // given
+    let params = V1Params {
+        l2_block_fullness_threshold: (101, 100),
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
+
+#[test]
+fn new__rejects_fullness_threshold_with_zero_denominator() {
+    // given
+    let params = V1Params {
+        l2_block_fullness_threshold: (0, 0),
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
+
+#[test]
+fn new__rejects_exec_gas_price_change_above_100_percent() {
+    // given
+    let params = V1Params {
+        exec_gas_price_change_percent: 101,
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
+
+#[test]
+fn new__rejects_exec_price_below_minimum() {
+    // given
+    let params = V1Params {
+        new_exec_price: 49,
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
+
+#[test]
+fn new__rejects_surge_threshold_above_100_percent() {
+    // given
+    let params = V1Params {
+        surge_threshold_percent: 101,
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
+
+#[test]
+fn new__rejects_surge_multiplier_below_100_percent() {
+    // given
+    let params = V1Params {
+        surge_multiplier_percent: 99,
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
+
+#[test]
+fn new__rejects_floor_decay_above_100_percent() {
+    // given
+    let params = V1Params {
+        floor_decay_percent: 101,
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
+
+#[test]
+fn new__rejects_min_floor_above_min_exec_gas_price() {
+    // given
+    let params = V1Params {
+        min_floor: 51,
+        ..valid_params()
+    };
+
+    // when
+    let result = AlgorithmUpdaterV1::new(params);
+
+    // then
+    assert!(matches!(result, Err(Error::InvalidParameters(_))));
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn normalize__raises_exec_price_below_min_to_min() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_min_exec_gas_price(100)
+        .with_starting_exec_gas_price(10)
+        .build();
+
+    // when
+    updater.normalize();
+
+    // then
+    assert_eq!(updater.algorithm().calculate(0), 100);
+}
+
+#[test]
+fn normalize__keeps_exec_price_above_min() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_min_exec_gas_price(100)
+        .with_starting_exec_gas_price(150)
+        .build();
+
+    // when
+    updater.normalize();
+
+    // then
+    assert_eq!(updater.new_exec_price, 150);
+}
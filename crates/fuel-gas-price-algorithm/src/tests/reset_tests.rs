@@ -0,0 +1,92 @@
+use super::*;
+
+#[test]
+fn reset__next_l2_block_update_expects_height_one() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_l2_block_height(100)
+        .with_starting_exec_gas_price(1000)
+        .build();
+
+    // when
+    updater.reset(10);
+
+    // then
+    let skipped = updater
+        .clone()
+        .update_l2_block_data(101, (50, 100), 1000, 100)
+        .unwrap_err();
+    assert_eq!(
+        skipped,
+        Error::SkippedL2Block {
+            expected: 1,
+            got: 101
+        }
+    );
+    updater
+        .update_l2_block_data(1, (50, 100), 1000, 100)
+        .unwrap();
+    assert_eq!(updater.l2_block_height, 1);
+}
+
+#[test]
+fn reset__sets_starting_price_but_keeps_configuration() {
+    // given
+    let min_exec_gas_price = 10;
+    let change_percent = 5;
+    let mut updater = UpdaterBuilder::new()
+        .with_min_exec_gas_price(min_exec_gas_price)
+        .with_exec_gas_price_change_percent(change_percent)
+        .with_starting_exec_gas_price(1000)
+        .build();
+
+    // when
+    updater.reset(500);
+
+    // then
+    assert_eq!(updater.new_exec_price, 500);
+    assert_eq!(updater.min_exec_gas_price, min_exec_gas_price);
+    assert_eq!(updater.exec_gas_price_change_percent, change_percent);
+}
+
+#[test]
+fn reset__starting_price_is_clamped_to_min() {
+    // given
+    let min_exec_gas_price = 10;
+    let mut updater = UpdaterBuilder::new()
+        .with_min_exec_gas_price(min_exec_gas_price)
+        .with_starting_exec_gas_price(1000)
+        .build();
+
+    // when
+    updater.reset(1);
+
+    // then
+    assert_eq!(updater.new_exec_price, min_exec_gas_price);
+}
+
+#[test]
+fn reset__clears_da_progress() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_da_recorded_block_height(5)
+        .with_l2_block_height(7)
+        .with_total_rewards(100)
+        .with_known_total_cost(200)
+        .with_projected_total_cost(300)
+        .with_unrecorded_blocks(vec![BlockBytes {
+            height: 6,
+            block_bytes: 1000,
+        }])
+        .build();
+
+    // when
+    updater.reset(0);
+
+    // then
+    assert_eq!(updater.da_recorded_block_height, 0);
+    assert_eq!(updater.total_da_rewards, 0);
+    assert_eq!(updater.latest_known_total_da_cost, 0);
+    assert_eq!(updater.projected_total_da_cost, 0);
+    assert!(updater.unrecorded_blocks.is_empty());
+}
@@ -0,0 +1,73 @@
+use super::*;
+use proptest::prelude::*;
+
+#[test]
+fn run_sequence__returns_the_price_after_each_block() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(1000)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .build();
+    let steps = [(1, 100, 100), (2, 100, 100), (3, 0, 100)];
+
+    // when
+    let prices = updater.run_sequence(&steps).unwrap();
+
+    // then
+    assert_eq!(prices, vec![1100, 1210, 1089]);
+    assert_eq!(updater.l2_block_height, 3);
+}
+
+#[test]
+fn run_sequence__stops_at_the_first_error() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(1000)
+        .with_exec_gas_price_change_percent(10)
+        .build();
+    let steps = [(1, 100, 100), (3, 100, 100), (4, 100, 100)];
+
+    // when
+    let result = updater.run_sequence(&steps);
+
+    // then
+    assert!(matches!(
+        result,
+        Err(Error::SkippedL2Block {
+            expected: 2,
+            got: 3
+        })
+    ));
+    assert_eq!(updater.l2_block_height, 1);
+}
+
+proptest! {
+    #[test]
+    fn run_sequence__price_never_drops_below_min(
+        fullness in prop::collection::vec((0u64..200, 0u64..200), 1..50),
+        starting_price in 0u64..10_000,
+        min_price in 0u64..10_000,
+        change_percent in 0u64..100,
+    ) {
+        // given
+        let mut updater = UpdaterBuilder::new()
+            .with_starting_exec_gas_price(starting_price)
+            .with_min_exec_gas_price(min_price)
+            .with_exec_gas_price_change_percent(change_percent)
+            .build();
+        let steps: Vec<_> = fullness
+            .into_iter()
+            .zip(1u32..)
+            .map(|((used, capacity), height)| (height, used, capacity))
+            .collect();
+
+        // when
+        let prices = updater.run_sequence(&steps).unwrap();
+
+        // then
+        prop_assert_eq!(prices.len(), steps.len());
+        prop_assert!(prices.iter().all(|price| *price >= min_price));
+        prop_assert_eq!(updater.l2_block_height, u32::try_from(steps.len()).unwrap());
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn simulate__matches_step_by_step_updates() {
+    // given
+    let config = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(1000)
+        .with_min_exec_gas_price(900)
+        .with_exec_gas_price_change_percent(5)
+        .with_l2_block_capacity_threshold(50)
+        .build();
+    let fullness_series = [(90, 100), (80, 100), (50, 100), (10, 100), (0, 100)];
+
+    // when
+    let actual = AlgorithmUpdaterV1::simulate(&config, &fullness_series);
+
+    // then
+    let mut updater = config.clone();
+    let expected: Vec<_> = fullness_series
+        .iter()
+        .zip(1u32..)
+        .map(|(fullness, height)| {
+            updater
+                .update_l2_block_data(height, *fullness, 1000, 0)
+                .unwrap();
+            updater.new_exec_price
+        })
+        .collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn simulate__does_not_modify_config() {
+    // given
+    let config = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(1000)
+        .with_exec_gas_price_change_percent(10)
+        .build();
+    let original = config.clone();
+
+    // when
+    let _ = AlgorithmUpdaterV1::simulate(&config, &[(100, 100), (100, 100)]);
+
+    // then
+    assert_eq!(config, original);
+}
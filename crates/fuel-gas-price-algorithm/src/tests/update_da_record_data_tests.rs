@@ -278,3 +278,172 @@ fn update__da_block_updates_projected_total_cost_with_known_and_guesses_on_top()
     let expected = new_known_total_cost + guessed_part;
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn update_da_record_data__zero_bytes_throws_error() {
+    // given
+    let mut updater = UpdaterBuilder::new().build();
+
+    let block_bytes = 0;
+    let block_cost = 100;
+    let blocks = vec![RecordedBlock {
+        height: 1,
+        block_bytes,
+        block_cost,
+    }];
+
+    // when
+    let actual_error = updater.update_da_record_data(blocks).unwrap_err();
+
+    // then
+    let expected_error = Error::CouldNotCalculateCostPerByte {
+        bytes: block_bytes,
+        cost: block_cost,
+    };
+    assert_eq!(actual_error, expected_error);
+}
+
+#[test]
+fn update_da_record_data__calculate_uses_recalculated_projection() {
+    // given
+    let da_cost_per_byte = 10;
+    let da_recorded_block_height = 10;
+    let l2_block_height = 12;
+    let known_total_cost = 1000;
+    let last_da_gas_price = 100_000;
+    let unrecorded_blocks = vec![
+        BlockBytes {
+            height: 11,
+            block_bytes: 100,
+        },
+        BlockBytes {
+            height: 12,
+            block_bytes: 100,
+        },
+    ];
+    let guessed_cost: u64 = unrecorded_blocks
+        .iter()
+        .map(|block| block.block_bytes * da_cost_per_byte)
+        .sum();
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_da_gas_price(last_da_gas_price)
+        .with_da_p_component(1)
+        .with_da_cost_per_byte(da_cost_per_byte)
+        .with_da_recorded_block_height(da_recorded_block_height)
+        .with_l2_block_height(l2_block_height)
+        .with_known_total_cost(known_total_cost)
+        .with_projected_total_cost(known_total_cost + guessed_cost)
+        .with_unrecorded_blocks(unrecorded_blocks)
+        .build();
+    let next_block_bytes = 100;
+    let price_before = updater.algorithm().calculate(next_block_bytes);
+
+    let new_cost_per_byte = 50;
+    let block_cost = 100 * new_cost_per_byte;
+    let blocks = vec![RecordedBlock {
+        height: 11,
+        block_bytes: 100,
+        block_cost,
+    }];
+
+    // when
+    updater.update_da_record_data(blocks).unwrap();
+    let price_after = updater.algorithm().calculate(next_block_bytes);
+
+    // then
+    let new_known_total_cost = known_total_cost + block_cost;
+    let new_projected_total_cost = new_known_total_cost + 100 * new_cost_per_byte;
+    assert_eq!(updater.projected_total_da_cost, new_projected_total_cost);
+    let expected_before = last_da_gas_price
+        + known_total_cost
+        + guessed_cost
+        + next_block_bytes * da_cost_per_byte;
+    assert_eq!(price_before, expected_before);
+    let expected_after = last_da_gas_price
+        + new_projected_total_cost
+        + next_block_bytes * new_cost_per_byte;
+    assert_eq!(price_after, expected_after);
+}
+
+#[test]
+fn update_da_record_data__removes_recorded_blocks_from_unrecorded_blocks() {
+    // given
+    let mut updater = UpdaterBuilder::new().build();
+    let fullness = (50, 100);
+    let gas_price = 100;
+    for height in 1..=5 {
+        let block_bytes = height as u64 * 1000;
+        updater
+            .update_l2_block_data(height, fullness, block_bytes, gas_price)
+            .unwrap();
+    }
+
+    let blocks = vec![
+        RecordedBlock {
+            height: 1,
+            block_bytes: 1000,
+            block_cost: 100,
+        },
+        RecordedBlock {
+            height: 2,
+            block_bytes: 2000,
+            block_cost: 200,
+        },
+        RecordedBlock {
+            height: 3,
+            block_bytes: 3000,
+            block_cost: 300,
+        },
+    ];
+
+    // when
+    updater.update_da_record_data(blocks).unwrap();
+
+    // then
+    let expected = vec![
+        BlockBytes {
+            height: 4,
+            block_bytes: 4000,
+        },
+        BlockBytes {
+            height: 5,
+            block_bytes: 5000,
+        },
+    ];
+    let actual = updater.unrecorded_blocks;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn update_da_record_data__reduces_the_unrecorded_blocks() {
+    // given
+    let mut updater = UpdaterBuilder::new().build();
+    for (height, block_bytes) in [(1, 100), (2, 200), (3, 300), (4, 400)] {
+        updater
+            .update_l2_block_data(height, (50, 100), block_bytes, 0)
+            .unwrap();
+    }
+    let unrecorded_before = updater.unrecorded_block_count();
+    let unrecorded_bytes_before = updater.unrecorded_bytes();
+    let blocks = vec![
+        RecordedBlock {
+            height: 1,
+            block_bytes: 100,
+            block_cost: 10,
+        },
+        RecordedBlock {
+            height: 2,
+            block_bytes: 200,
+            block_cost: 20,
+        },
+    ];
+
+    // when
+    updater.update_da_record_data(blocks).unwrap();
+
+    // then
+    assert_eq!(unrecorded_before, 4);
+    assert_eq!(unrecorded_bytes_before, 1000);
+    assert_eq!(updater.unrecorded_block_count(), 2);
+    assert_eq!(updater.unrecorded_bytes(), 700);
+}
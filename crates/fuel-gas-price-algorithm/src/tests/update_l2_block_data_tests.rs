@@ -187,6 +187,66 @@ fn update_l2_block_data__above_threshold_will_increase_exec_gas_price() {
     let actual = updater.new_exec_price;
     assert_eq!(actual, expected);
 }
+#[test]
+fn update_l2_block_data__below_surge_threshold_steps_normally() {
+    // given
+    let starting_exec_gas_price = 200;
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(starting_exec_gas_price)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_surge(95, 200)
+        .build();
+
+    // when
+    updater
+        .update_l2_block_data(1, (95, 100), 1000, 200)
+        .unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 220);
+}
+
+#[test]
+fn update_l2_block_data__above_surge_threshold_multiplies_exec_gas_price() {
+    // given
+    let starting_exec_gas_price = 200;
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(starting_exec_gas_price)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_surge(95, 200)
+        .build();
+
+    // when
+    updater
+        .update_l2_block_data(1, (96, 100), 1000, 200)
+        .unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 400);
+}
+
+#[test]
+fn update_l2_block_data__disabled_surge_steps_normally_on_full_blocks() {
+    // given
+    let starting_exec_gas_price = 200;
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(starting_exec_gas_price)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_surge(0, 200)
+        .build();
+
+    // when
+    updater
+        .update_l2_block_data(1, (100, 100), 1000, 200)
+        .unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 220);
+}
+
 #[test]
 fn update_l2_block_data__exec_price_will_not_go_below_min() {
     // given
@@ -272,3 +332,369 @@ fn update_l2_block_data__updates_profit_avg() {
     let actual = updater.profit_avg;
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn update_l2_block_data__adds_block_to_unrecorded_blocks() {
+    // given
+    let mut updater = UpdaterBuilder::new().build();
+
+    let height = 1;
+    let fullness = (50, 100);
+    let block_bytes = 1000;
+    let new_gas_price = 100;
+
+    // when
+    updater
+        .update_l2_block_data(height, fullness, block_bytes, new_gas_price)
+        .unwrap();
+
+    // then
+    let expected = vec![BlockBytes {
+        height,
+        block_bytes,
+    }];
+    let actual = updater.unrecorded_blocks;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn update_l2_block_data__half_ratio_threshold_behaves_like_fifty_percent() {
+    // given
+    let starting_exec_gas_price = 100;
+    let change_percent = 10;
+    let updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(starting_exec_gas_price)
+        .with_exec_gas_price_change_percent(change_percent)
+        .with_l2_block_fullness_threshold(1, 2)
+        .build();
+
+    // when
+    let prices = [(49, 100), (50, 100), (51, 100)].map(|fullness| {
+        let mut updater = updater.clone();
+        updater
+            .update_l2_block_data(1, fullness, 1000, 200)
+            .unwrap();
+        updater.new_exec_price
+    });
+
+    // then
+    assert_eq!(prices, [90, 100, 110]);
+}
+
+#[test]
+fn update_l2_block_data__sub_percent_threshold_is_respected() {
+    // given
+    let starting_exec_gas_price = 100;
+    let change_percent = 10;
+    let updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(starting_exec_gas_price)
+        .with_exec_gas_price_change_percent(change_percent)
+        .with_l2_block_fullness_threshold(505, 1000)
+        .build();
+
+    // when
+    let prices = [(504, 1000), (505, 1000), (101, 200), (506, 1000)].map(|fullness| {
+        let mut updater = updater.clone();
+        updater
+            .update_l2_block_data(1, fullness, 1000, 200)
+            .unwrap();
+        updater.new_exec_price
+    });
+
+    // then
+    assert_eq!(prices, [90, 100, 100, 110]);
+}
+
+#[test]
+fn update_l2_block_data__huge_capacity_does_not_overflow_threshold_comparison() {
+    // given
+    let starting_exec_gas_price = 100;
+    let change_percent = 10;
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(starting_exec_gas_price)
+        .with_exec_gas_price_change_percent(change_percent)
+        .with_l2_block_fullness_threshold(u64::MAX - 1, u64::MAX)
+        .build();
+
+    // when
+    updater
+        .update_l2_block_data(1, (u64::MAX, u64::MAX), 1000, 200)
+        .unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 110);
+}
+
+#[test]
+fn update_l2_block_data__decreases_only_after_required_consecutive_low_blocks() {
+    // given
+    let starting_exec_gas_price = 100;
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(starting_exec_gas_price)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_consecutive_low_blocks_required(3)
+        .build();
+    let low_fullness = (40, 100);
+
+    // when
+    let mut prices = vec![];
+    for height in 1..=3 {
+        updater
+            .update_l2_block_data(height, low_fullness, 1000, 100)
+            .unwrap();
+        prices.push(updater.new_exec_price);
+    }
+
+    // then
+    assert_eq!(prices, vec![100, 100, 90]);
+    assert_eq!(updater.consecutive_low_blocks, 0);
+}
+
+#[test]
+fn update_l2_block_data__high_block_restarts_the_low_block_count() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_consecutive_low_blocks_required(3)
+        .build();
+    let fullness_per_block = [(40, 100), (40, 100), (60, 100), (40, 100), (40, 100)];
+
+    // when
+    let mut prices = vec![];
+    for (fullness, height) in fullness_per_block.into_iter().zip(1u32..) {
+        updater
+            .update_l2_block_data(height, fullness, 1000, 100)
+            .unwrap();
+        prices.push(updater.new_exec_price);
+    }
+
+    // then
+    assert_eq!(prices, vec![100, 100, 110, 110, 110]);
+    assert_eq!(updater.consecutive_low_blocks, 2);
+}
+
+#[test]
+fn update_l2_block_data__flags_updates_clamped_to_min() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(105)
+        .with_min_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .build();
+
+    // when
+    updater
+        .update_l2_block_data(1, (0, 100), 1000, 100)
+        .unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 100);
+    assert!(updater.last_update_clamped_to_min);
+}
+
+#[test]
+fn update_l2_block_data__clears_clamped_flag_when_price_rises() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(105)
+        .with_min_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .build();
+    updater
+        .update_l2_block_data(1, (0, 100), 1000, 100)
+        .unwrap();
+
+    // when
+    updater
+        .update_l2_block_data(2, (100, 100), 1000, 100)
+        .unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 110);
+    assert!(!updater.last_update_clamped_to_min);
+}
+
+#[test]
+fn update_l2_block_data__floor_holds_until_enough_clamped_blocks() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(100)
+        .with_min_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_floor_decay(10, 3, 50)
+        .build();
+
+    // when
+    for height in 1..=2 {
+        updater
+            .update_l2_block_data(height, (0, 100), 1000, 100)
+            .unwrap();
+    }
+
+    // then
+    assert_eq!(updater.min_exec_gas_price, 100);
+    assert_eq!(updater.new_exec_price, 100);
+}
+
+#[test]
+fn update_l2_block_data__floor_decays_towards_min_floor_during_low_usage() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(100)
+        .with_min_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_floor_decay(10, 3, 50)
+        .build();
+
+    // when
+    let mut floors = vec![];
+    for height in 1..=100 {
+        updater
+            .update_l2_block_data(height, (0, 100), 1000, 100)
+            .unwrap();
+        floors.push(updater.min_exec_gas_price);
+    }
+
+    // then
+    assert_eq!(floors[2], 90);
+    assert!(floors.windows(2).all(|pair| pair[1] <= pair[0]));
+    assert!(floors.iter().all(|floor| *floor >= 50));
+    assert_eq!(updater.min_exec_gas_price, 50);
+    assert_eq!(updater.new_exec_price, 50);
+}
+
+#[test]
+fn update_l2_block_data__floor_does_not_decay_when_disabled() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(100)
+        .with_min_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .with_floor_decay(0, 3, 50)
+        .build();
+
+    // when
+    for height in 1..=10 {
+        updater
+            .update_l2_block_data(height, (0, 100), 1000, 100)
+            .unwrap();
+    }
+
+    // then
+    assert_eq!(updater.min_exec_gas_price, 100);
+}
+
+#[test]
+fn update_l2_block_data__zero_capacity_keeps_the_exec_gas_price() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .build();
+
+    // when
+    updater.update_l2_block_data(1, (0, 0), 1000, 100).unwrap();
+
+    // then
+    assert_eq!(updater.new_exec_price, 100);
+}
+
+#[test]
+fn update_l2_block_data__strict_mode_reports_price_overflow() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(u64::MAX / 2)
+        .with_exec_gas_price_change_percent(100)
+        .with_l2_block_capacity_threshold(50)
+        .with_strict_overflow(true)
+        .build();
+    let unchanged = updater.clone();
+
+    // when
+    let result = updater.update_l2_block_data(1, (100, 100), 1000, 100);
+
+    // then
+    assert_eq!(
+        result,
+        Err(Error::PriceOverflow {
+            price: u64::MAX / 2,
+            change_percent: 100,
+        })
+    );
+    assert_eq!(updater, unchanged);
+}
+
+#[test]
+fn update_l2_block_data__strict_mode_ignores_low_blocks_that_do_not_decrease_the_price() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(u64::MAX / 2)
+        .with_exec_gas_price_change_percent(100)
+        .with_l2_block_capacity_threshold(50)
+        .with_consecutive_low_blocks_required(2)
+        .with_strict_overflow(true)
+        .build();
+
+    // when
+    let first = updater.update_l2_block_data(1, (0, 100), 1000, 100);
+    let second = updater.update_l2_block_data(2, (0, 100), 1000, 100);
+
+    // then
+    assert_eq!(first, Ok(()));
+    assert_eq!(
+        second,
+        Err(Error::PriceOverflow {
+            price: u64::MAX / 2,
+            change_percent: 100,
+        })
+    );
+}
+
+#[test]
+fn update_l2_block_data__lenient_mode_saturates_price_overflow() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(u64::MAX / 2)
+        .with_exec_gas_price_change_percent(100)
+        .with_l2_block_capacity_threshold(50)
+        .build();
+
+    // when
+    let result = updater.update_l2_block_data(1, (100, 100), 1000, 100);
+
+    // then
+    assert!(result.is_ok());
+}
+
+#[test]
+fn preview_l2_block__matches_update_without_advancing_height() {
+    // given
+    let mut updater = UpdaterBuilder::new()
+        .with_starting_exec_gas_price(100)
+        .with_exec_gas_price_change_percent(10)
+        .with_l2_block_capacity_threshold(50)
+        .build();
+    let fullness = (90, 100);
+    let height_before = updater.l2_block_height;
+
+    // when
+    let preview = updater.preview_l2_block(fullness);
+
+    // then
+    assert_eq!(updater.l2_block_height, height_before);
+    assert_eq!(updater.new_exec_price, 100);
+    updater
+        .update_l2_block_data(height_before + 1, fullness, 1000, 100)
+        .unwrap();
+    assert_eq!(updater.new_exec_price, preview);
+    assert_eq!(updater.l2_block_height, height_before + 1);
+}
@@ -1,19 +1,42 @@
 use prometheus_client::{
+    encoding::{
+        EncodeLabelSet,
+        EncodeLabelValue,
+    },
     metrics::{
         counter::Counter,
+        family::Family,
         histogram::Histogram,
     },
     registry::Registry,
 };
 use std::sync::OnceLock;
 
+/// An operation performed on a table of the database
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub enum ColumnOperation {
+    Get,
+    Insert,
+    Remove,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ColumnOperationLabel {
+    // the name of the column
+    column: &'static str,
+    operation: ColumnOperation,
+}
+
 pub struct DatabaseMetrics {
     pub registry: Registry,
+    /// Holds only the `column_operations`, the other database metrics aren't exported.
+    pub column_operations_registry: Registry,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
     pub write_meter: Counter,
     pub read_meter: Counter,
     pub bytes_written: Histogram,
     pub bytes_read: Histogram,
+    pub column_operations: Family<ColumnOperationLabel, Counter>,
 }
 
 impl DatabaseMetrics {
@@ -31,12 +54,46 @@ impl DatabaseMetrics {
 
         DatabaseMetrics {
             registry,
+            column_operations_registry: Registry::default(),
             write_meter,
             read_meter,
             bytes_read: bytes_read_histogram,
             bytes_written: bytes_written_histogram,
+            column_operations: Family::default(),
         }
     }
+
+    pub fn record_column_operation(
+        &self,
+        column: &'static str,
+        operation: ColumnOperation,
+    ) {
+        self.column_operations
+            .get_or_create(&ColumnOperationLabel { column, operation })
+            .inc();
+    }
+
+    pub fn record_column_operations(
+        &self,
+        column: &'static str,
+        operation: ColumnOperation,
+        count: u64,
+    ) {
+        self.column_operations
+            .get_or_create(&ColumnOperationLabel { column, operation })
+            .inc_by(count);
+    }
+
+    /// Returns the number of `operation`s recorded on the `column`.
+    pub fn column_operations_count(
+        &self,
+        column: &'static str,
+        operation: ColumnOperation,
+    ) -> u64 {
+        self.column_operations
+            .get_or_create(&ColumnOperationLabel { column, operation })
+            .get()
+    }
 }
 
 pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
@@ -60,6 +117,11 @@ pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
         "Histogram containing values of amount of bytes written per operation",
         metrics.bytes_written.clone(),
     );
+    metrics.column_operations_registry.register(
+        "Database_Column_Operations",
+        "Number of get, insert and remove operations per database column",
+        metrics.column_operations.clone(),
+    );
 
     metrics
 }
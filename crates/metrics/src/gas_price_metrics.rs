@@ -0,0 +1,91 @@
+use prometheus_client::{
+    metrics::{
+        counter::Counter,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+pub struct GasPriceMetrics {
+    pub registry: Registry,
+    pub exec_gas_price: Gauge,
+    pub last_fullness_percent: Gauge,
+    pub price_increases: Counter,
+    pub price_decreases: Counter,
+    pub min_clamp_hits: Counter,
+    pub unrecorded_blocks: Gauge,
+    pub unrecorded_bytes: Gauge,
+}
+
+impl Default for GasPriceMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let exec_gas_price = Gauge::default();
+        let last_fullness_percent = Gauge::default();
+        let price_increases = Counter::default();
+        let price_decreases = Counter::default();
+        let min_clamp_hits = Counter::default();
+        let unrecorded_blocks = Gauge::default();
+        let unrecorded_bytes = Gauge::default();
+
+        registry.register(
+            "gas_price_exec_gas_price",
+            "The execution gas price for the next block",
+            exec_gas_price.clone(),
+        );
+
+        registry.register(
+            "gas_price_last_fullness_percent",
+            "The fullness of the last L2 block as a percentage of its capacity",
+            last_fullness_percent.clone(),
+        );
+
+        registry.register(
+            "gas_price_increases",
+            "The number of L2 blocks after which the execution gas price increased",
+            price_increases.clone(),
+        );
+
+        registry.register(
+            "gas_price_decreases",
+            "The number of L2 blocks after which the execution gas price decreased",
+            price_decreases.clone(),
+        );
+
+        registry.register(
+            "gas_price_min_clamp_hits",
+            "The number of L2 blocks after which the execution gas price was held at its minimum",
+            min_clamp_hits.clone(),
+        );
+
+        registry.register(
+            "gas_price_unrecorded_blocks",
+            "The number of L2 blocks not yet recorded on the DA layer",
+            unrecorded_blocks.clone(),
+        );
+
+        registry.register(
+            "gas_price_unrecorded_bytes",
+            "The total size of the L2 blocks not yet recorded on the DA layer",
+            unrecorded_bytes.clone(),
+        );
+
+        Self {
+            registry,
+            exec_gas_price,
+            last_fullness_percent,
+            price_increases,
+            price_decreases,
+            min_clamp_hits,
+            unrecorded_blocks,
+            unrecorded_bytes,
+        }
+    }
+}
+
+static GAS_PRICE_METRICS: OnceLock<GasPriceMetrics> = OnceLock::new();
+pub fn gas_price_metrics() -> &'static GasPriceMetrics {
+    GAS_PRICE_METRICS.get_or_init(GasPriceMetrics::default)
+}
@@ -1,4 +1,6 @@
 use crate::{
+    core_metrics::database_metrics,
+    gas_price_metrics::gas_price_metrics,
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
@@ -50,6 +52,14 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body();
     }
 
+    if encode(&mut encoded, &gas_price_metrics().registry).is_err() {
+        return error_body();
+    }
+
+    if encode(&mut encoded, &database_metrics().column_operations_registry).is_err() {
+        return error_body();
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
@@ -11,6 +11,9 @@ pub struct Config {
     pub metrics: bool,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// Skips the interval ticks at which the transaction pool is empty, instead of
+    /// producing an empty block. Only affects the interval triggers.
+    pub skip_empty_blocks: bool,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -22,6 +25,7 @@ impl Default for Config {
             metrics: false,
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            skip_empty_blocks: false,
         }
     }
 }
@@ -37,4 +41,7 @@ pub enum Trigger {
     Never,
     /// A new block is produced periodically. Used to simulate consensus block delay.
     Interval { block_time: Duration },
+    /// A new block is produced periodically, at wall-clock times that are multiples of
+    /// `block_time` (in whole seconds) since the Unix epoch.
+    AlignedInterval { block_time: Duration },
 }
@@ -63,7 +63,11 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
-    time::Duration,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
 };
 use tokio::{
     sync::{
@@ -140,6 +144,7 @@ pub struct MainTask<T, B, I> {
     last_timestamp: Tai64,
     last_block_created: Instant,
     trigger: Trigger,
+    skip_empty_blocks: bool,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
     sync_task_handle: ServiceRunner<SyncTask>,
@@ -171,6 +176,7 @@ where
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            skip_empty_blocks,
             ..
         } = config;
 
@@ -196,6 +202,7 @@ where
             last_timestamp,
             last_block_created,
             trigger,
+            skip_empty_blocks,
             timer: DeadlineClock::new(),
             sync_task_handle,
         }
@@ -225,11 +232,15 @@ where
                     let duration = self.last_block_created.elapsed();
                     increase_time(self.last_timestamp, duration)
                 }
-                Trigger::Interval { block_time } => {
+                Trigger::Interval { block_time }
+                | Trigger::AlignedInterval { block_time } => {
                     increase_time(self.last_timestamp, block_time)
                 }
             },
             RequestType::Trigger => {
+                if let Trigger::AlignedInterval { block_time } = self.trigger {
+                    return Ok(aligned_time(self.last_timestamp, block_time))
+                }
                 let now = Tai64::now();
                 if now > self.last_timestamp {
                     Ok(now)
@@ -382,6 +393,16 @@ where
                     .set_deadline(deadline, OnConflict::Overwrite)
                     .await;
             }
+            (Trigger::AlignedInterval { block_time }, RequestType::Trigger) => {
+                self.timer
+                    .set_timeout(until_aligned_instant(block_time), OnConflict::Min)
+                    .await;
+            }
+            (Trigger::AlignedInterval { block_time }, RequestType::Manual) => {
+                self.timer
+                    .set_timeout(until_aligned_instant(block_time), OnConflict::Overwrite)
+                    .await;
+            }
         }
 
         Ok(())
@@ -397,7 +418,9 @@ where
                 }
                 Ok(())
             }
-            Trigger::Never | Trigger::Interval { .. } => Ok(()),
+            Trigger::Never
+            | Trigger::Interval { .. }
+            | Trigger::AlignedInterval { .. } => Ok(()),
         }
     }
 
@@ -407,7 +430,26 @@ where
                 unreachable!("Timer is never set in this mode");
             }
             // In the Interval mode the timer expires only when a new block should be created.
-            Trigger::Interval { .. } => {
+            Trigger::Interval { block_time } => {
+                if self.skip_empty_blocks && self.txpool.pending_number() == 0 {
+                    self.timer
+                        .set_timeout(block_time, OnConflict::Overwrite)
+                        .await;
+                    return Ok(())
+                }
+                self.produce_next_block().await?;
+                Ok(())
+            }
+            Trigger::AlignedInterval { block_time } => {
+                if self.skip_empty_blocks && self.txpool.pending_number() == 0 {
+                    self.timer
+                        .set_timeout(
+                            until_aligned_instant(block_time),
+                            OnConflict::Overwrite,
+                        )
+                        .await;
+                    return Ok(())
+                }
                 self.produce_next_block().await?;
                 Ok(())
             }
@@ -444,6 +486,11 @@ where
                     .set_timeout(block_time, OnConflict::Overwrite)
                     .await;
             }
+            Trigger::AlignedInterval { block_time } => {
+                self.timer
+                    .set_timeout(until_aligned_instant(block_time), OnConflict::Overwrite)
+                    .await;
+            }
         };
 
         Ok(self)
@@ -590,3 +637,40 @@ fn increase_time(time: Tai64, duration: Duration) -> anyhow::Result<Tai64> {
         ))?;
     Ok(Tai64(timestamp))
 }
+
+/// The period of the `AlignedInterval` trigger in whole seconds, at least one.
+fn aligned_period(block_time: Duration) -> u64 {
+    block_time.as_secs().max(1)
+}
+
+/// Returns the time left until the next wall-clock instant aligned to `block_time`.
+fn until_aligned_instant(block_time: Duration) -> Duration {
+    let period = aligned_period(block_time);
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let into_period = Duration::from_secs(
+        since_epoch
+            .as_secs()
+            .checked_rem(period)
+            .unwrap_or_default(),
+    )
+    .saturating_add(Duration::from_nanos(u64::from(since_epoch.subsec_nanos())));
+    Duration::from_secs(period).saturating_sub(into_period)
+}
+
+/// Returns the latest wall-clock time aligned to `block_time`, or the first aligned time
+/// after `last_timestamp` if the latest one isn't after it.
+fn aligned_time(last_timestamp: Tai64, block_time: Duration) -> Tai64 {
+    let period = i64::try_from(aligned_period(block_time)).unwrap_or(i64::MAX);
+    let align = |unix: i64| {
+        unix.saturating_sub(unix.checked_rem_euclid(period).unwrap_or_default())
+    };
+    let latest = align(Tai64::now().to_unix());
+    let last = last_timestamp.to_unix();
+    if latest > last {
+        Tai64::from_unix(latest)
+    } else {
+        Tai64::from_unix(align(last).saturating_add(period))
+    }
+}
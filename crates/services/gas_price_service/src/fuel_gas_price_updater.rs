@@ -1,20 +1,114 @@
 use crate::UpdateAlgorithm;
-use fuel_core_types::fuel_types::BlockHeight;
+use anyhow::anyhow;
+use fuel_core_metrics::gas_price_metrics::{
+    gas_price_metrics,
+    GasPriceMetrics,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        header::ConsensusParametersVersion,
+    },
+    fuel_tx::{
+        field::{
+            MintAmount,
+            MintGasPrice,
+        },
+        ConsensusParameters,
+        Transaction,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+    },
+    services::executor::{
+        TransactionExecutionResult,
+        TransactionExecutionStatus,
+    },
+};
 use fuel_gas_price_algorithm::{
     AlgorithmUpdaterV1,
-    AlgorithmV1,
     RecordedBlock,
 };
+use std::{
+    collections::VecDeque,
+    time::Duration,
+};
 
 #[cfg(test)]
 mod tests;
 
 pub struct FuelGasPriceUpdater<L2, Metadata> {
-    inner: AlgorithmUpdaterV1,
+    inner: Box<dyn GasPriceStrategy>,
     l2_block_source: L2,
     metadata_storage: Metadata,
 }
 
+/// A pricing strategy driven by the [`FuelGasPriceUpdater`] with the data of each new
+/// L2 block. [`AlgorithmUpdaterV1`] is the default strategy, integrators can plug in
+/// their own with [`FuelGasPriceUpdater::with_strategy`].
+pub trait GasPriceStrategy: Send + Sync {
+    /// Updates the strategy with the data of the L2 block at `height`.
+    fn update_l2_block_data(
+        &mut self,
+        height: u32,
+        fullness: (u64, u64),
+        block_bytes: u64,
+        gas_price: u64,
+    ) -> anyhow::Result<()>;
+
+    /// The gas price of the next block for a block of `block_bytes` size.
+    fn calculate(&self, block_bytes: u64) -> u64;
+
+    /// The height of the last L2 block applied to the strategy. The updater fetches
+    /// the block right above it next.
+    fn l2_block_height(&self) -> u32;
+
+    /// The versioned metadata to persist after each update, or `None` if the strategy
+    /// has no state worth persisting.
+    fn metadata(&self) -> Option<UpdaterMetadata>;
+
+    /// Clones the strategy into a snapshot that prices the next block.
+    fn boxed_clone(&self) -> Box<dyn GasPriceStrategy>;
+}
+
+impl GasPriceStrategy for AlgorithmUpdaterV1 {
+    fn update_l2_block_data(
+        &mut self,
+        height: u32,
+        fullness: (u64, u64),
+        block_bytes: u64,
+        gas_price: u64,
+    ) -> anyhow::Result<()> {
+        let previous_exec_price = self.new_exec_price;
+        AlgorithmUpdaterV1::update_l2_block_data(
+            self,
+            height,
+            fullness,
+            block_bytes,
+            gas_price,
+        )?;
+        record_metrics(gas_price_metrics(), previous_exec_price, fullness, self);
+        Ok(())
+    }
+
+    fn calculate(&self, block_bytes: u64) -> u64 {
+        self.algorithm().calculate(block_bytes)
+    }
+
+    fn l2_block_height(&self) -> u32 {
+        self.l2_block_height
+    }
+
+    fn metadata(&self) -> Option<UpdaterMetadata> {
+        Some(self.clone().into())
+    }
+
+    fn boxed_clone(&self) -> Box<dyn GasPriceStrategy> {
+        Box::new(self.clone())
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Failed to find L2 block at height {block_height:?}: {source_error:?}")]
@@ -24,9 +118,43 @@ pub enum Error {
     },
     #[error("Failed to find DA records: {0:?}")]
     CouldNotFetchDARecord(anyhow::Error),
+    #[error("Failed to fetch the metadata: {source_error:?}")]
+    CouldNotFetchMetadata {
+        source_error: anyhow::Error,
+        /// Whether the failure is transient, so that the read may succeed if retried
+        retryable: bool,
+    },
+    #[error(
+        "Failed to replay the L2 block at height {block_height:?}: {source_error:?}"
+    )]
+    CouldNotReplayL2Block {
+        block_height: BlockHeight,
+        source_error: anyhow::Error,
+    },
+    #[error("The block range {start:?}..={end:?} is empty")]
+    EmptyBlockRange {
+        start: BlockHeight,
+        end: BlockHeight,
+    },
 }
 
-type Result<T> = std::result::Result<T, Error>;
+impl Error {
+    /// Whether the failed operation may succeed if retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Error::CouldNotFetchMetadata {
+                retryable: true,
+                ..
+            }
+        )
+    }
+}
+
+/// The result of the operations of the gas price updater.
+pub type GasPriceResult<T> = std::result::Result<T, Error>;
+
+type Result<T> = GasPriceResult<T>;
 
 // Info required about the l2 block for the gas price algorithm
 #[derive(Debug, Clone)]
@@ -35,26 +163,325 @@ pub struct BlockInfo {
     pub height: u32,
     // Fullness of block gas usage vs max block gas
     pub fullness: (u64, u64),
+    // Fullness averaged over the recent blocks, the same as `fullness` unless the block
+    // comes from a `SmoothedL2BlockSource`
+    pub smoothed_fullness: (u64, u64),
     // Block size in bytes
     pub block_bytes: u64,
     // Gas price of the block
     pub gas_price: u64,
+    // Fee paid to the coinbase by the block
+    pub fee: u64,
+    // Max gas of the block
+    pub block_gas_limit: u64,
+    // How the used gas of the fullness was obtained
+    pub fullness_source: FullnessSource,
+}
+
+/// The source of the used gas in [`BlockInfo::fullness`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FullnessSource {
+    /// The gas used reported by the execution of the block
+    GasUsed,
+    /// Derived from the fee and the gas price of the coinbase `Mint` transaction
+    Fee,
+}
+
+/// The parameters of the chain needed to derive the fullness of a block from its fee
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockCapacity {
+    /// Max gas of a block
+    pub gas_limit: u64,
+    /// The factor the fee of a block is divided by
+    pub gas_price_factor: u64,
+    /// Whether the coinbase `Mint` transaction is left out of [`BlockInfo::block_bytes`],
+    /// so the size reflects only the user transactions. The `Mint` doesn't consume gas,
+    /// so the used gas of the fullness is the same either way.
+    pub exclude_mint: bool,
+}
+
+impl BlockCapacity {
+    pub fn from_consensus_parameters(params: &ConsensusParameters) -> Self {
+        Self {
+            gas_limit: params.block_gas_limit(),
+            gas_price_factor: params.fee_params().gas_price_factor(),
+            exclude_mint: false,
+        }
+    }
+
+    /// Returns the capacity of `block` from the consensus parameters of its version.
+    pub fn for_block<P>(block: &Block<Transaction>, provider: &P) -> Result<Self>
+    where
+        P: ConsensusParamsProvider + ?Sized,
+    {
+        let version = block.header().consensus_parameters_version;
+        let params =
+            provider
+                .get_params(version)
+                .ok_or_else(|| Error::CouldNotFetchL2Block {
+                    block_height: *block.header().height(),
+                    source_error: anyhow!(
+                        "Missing the consensus parameters of the version {version}"
+                    ),
+                })?;
+        Ok(Self::from_consensus_parameters(&params))
+    }
+}
+
+/// Provides the consensus parameters of a version, so the [`BlockCapacity`] of a block
+/// can be derived without depending on the database directly.
+pub trait ConsensusParamsProvider {
+    /// Returns the consensus parameters of the `version`, or `None` if they are unknown.
+    fn get_params(
+        &self,
+        version: ConsensusParametersVersion,
+    ) -> Option<ConsensusParameters>;
+}
+
+/// Derives the [`BlockInfo`] of `block` from its coinbase `Mint` transaction, which is
+/// the last transaction of every produced block.
+///
+/// Blocks minted with a gas price of zero (genesis or free-gas chains) are reported
+/// with a fullness of `(0, block_gas_limit)`, since their used gas can't be recovered
+/// from the fee.
+pub fn get_block_info(
+    block: &Block<Transaction>,
+    capacity: &BlockCapacity,
+) -> Result<BlockInfo> {
+    get_block_info_with_gas_used(block, None, capacity)
+}
+
+/// Same as [`get_block_info`], but the fullness is taken from `gas_used` when it is
+/// known, for example from the execution statuses of the imported block (see
+/// [`block_gas_used`]). The fee-based derivation is only used as a fallback.
+pub fn get_block_info_with_gas_used(
+    block: &Block<Transaction>,
+    gas_used: Option<u64>,
+    capacity: &BlockCapacity,
+) -> Result<BlockInfo> {
+    let BlockCapacity {
+        gas_limit: block_gas_limit,
+        gas_price_factor,
+        exclude_mint,
+    } = *capacity;
+    let height = *block.header().height();
+    let Some(Transaction::Mint(mint)) = block.transactions().last() else {
+        return Err(Error::CouldNotFetchL2Block {
+            block_height: height,
+            source_error: anyhow!("Block is missing its coinbase `Mint` transaction"),
+        })
+    };
+    let fee = *mint.mint_amount();
+    let gas_price = *mint.gas_price();
+    let (used_gas, fullness_source) = match gas_used {
+        Some(gas_used) => (gas_used.min(block_gas_limit), FullnessSource::GasUsed),
+        None => (
+            block_used_gas(fee, gas_price, gas_price_factor, block_gas_limit),
+            FullnessSource::Fee,
+        ),
+    };
+    let transactions = match block.transactions().split_last() {
+        Some((_, user_transactions)) if exclude_mint => user_transactions,
+        _ => block.transactions(),
+    };
+    let block_bytes = transactions.iter().fold(0u64, |bytes, tx| {
+        bytes.saturating_add(u64::try_from(tx.size()).unwrap_or(u64::MAX))
+    });
+    let info = BlockInfo {
+        height: height.into(),
+        fullness: (used_gas, block_gas_limit),
+        smoothed_fullness: (used_gas, block_gas_limit),
+        block_bytes,
+        gas_price,
+        fee,
+        block_gas_limit,
+        fullness_source,
+    };
+    Ok(info)
+}
+
+/// Sums the gas used by the transactions of a block from their execution statuses.
+pub fn block_gas_used(statuses: &[TransactionExecutionStatus]) -> u64 {
+    statuses
+        .iter()
+        .fold(0u64, |gas_used, status| match status.result {
+            TransactionExecutionResult::Success { total_gas, .. }
+            | TransactionExecutionResult::Failed { total_gas, .. } => {
+                gas_used.saturating_add(total_gas)
+            }
+        })
+}
+
+// The fee is `used_gas * gas_price / gas_price_factor`, so the used gas is recovered by
+// inverting it. Without a gas price nothing is charged and the block is reported as empty.
+fn block_used_gas(
+    fee: u64,
+    gas_price: u64,
+    gas_price_factor: u64,
+    block_gas_limit: u64,
+) -> u64 {
+    let scaled_fee = u128::from(fee).saturating_mul(u128::from(gas_price_factor));
+    let used_gas = scaled_fee
+        .checked_div(u128::from(gas_price))
+        .unwrap_or_default();
+    u64::try_from(used_gas)
+        .unwrap_or(u64::MAX)
+        .min(block_gas_limit)
 }
 #[async_trait::async_trait]
 pub trait L2BlockSource: Send + Sync {
     async fn get_l2_block(&self, height: BlockHeight) -> Result<BlockInfo>;
 }
 
+/// An [`L2BlockSource`] that sets [`BlockInfo::smoothed_fullness`] of the blocks of the
+/// `inner` source to the average fullness of the last `window` blocks returned, the block
+/// itself included. The raw `fullness` is left as is.
+///
+/// The blocks are expected to be requested in order, as the [`FuelGasPriceUpdater`] does.
+pub struct SmoothedL2BlockSource<L2> {
+    inner: L2,
+    window: usize,
+    recent: tokio::sync::Mutex<VecDeque<(u64, u64)>>,
+}
+
+impl<L2> SmoothedL2BlockSource<L2> {
+    /// Wraps `inner`, averaging over `window` blocks. A window of `0` is treated as `1`,
+    /// which doesn't smooth at all.
+    pub fn new(inner: L2, window: usize) -> Self {
+        let window = window.max(1);
+        Self {
+            inner,
+            window,
+            recent: tokio::sync::Mutex::new(VecDeque::with_capacity(window)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<L2> L2BlockSource for SmoothedL2BlockSource<L2>
+where
+    L2: L2BlockSource,
+{
+    async fn get_l2_block(&self, height: BlockHeight) -> Result<BlockInfo> {
+        let mut block = self.inner.get_l2_block(height).await?;
+        let mut recent = self.recent.lock().await;
+        recent.push_back(block.fullness);
+        while recent.len() > self.window {
+            recent.pop_front();
+        }
+        let (used, capacity) =
+            recent
+                .iter()
+                .fold((0u128, 0u128), |(used, capacity), fullness| {
+                    (
+                        used.saturating_add(u128::from(fullness.0)),
+                        capacity.saturating_add(u128::from(fullness.1)),
+                    )
+                });
+        let blocks = u128::try_from(recent.len()).unwrap_or(u128::MAX);
+        let average = |sum: u128| {
+            u64::try_from(sum.checked_div(blocks).unwrap_or_default()).unwrap_or(u64::MAX)
+        };
+        block.smoothed_fullness = (average(used), average(capacity));
+        Ok(block)
+    }
+}
+
+/// Returns the average gas price of the L2 blocks from `start` to `end` (inclusive),
+/// weighted by the gas used by each block, so that full blocks dominate the average. If
+/// no gas was used in the range, every block has the same weight.
+pub async fn average_gas_price<L2>(
+    l2_block_source: &L2,
+    start: BlockHeight,
+    end: BlockHeight,
+) -> Result<u64>
+where
+    L2: L2BlockSource,
+{
+    if start > end {
+        return Err(Error::EmptyBlockRange { start, end });
+    }
+    let mut weighted_sum = 0u128;
+    let mut total_weight = 0u128;
+    let mut sum = 0u128;
+    let mut blocks = 0u128;
+    for height in u32::from(start)..=u32::from(end) {
+        let block = l2_block_source.get_l2_block(height.into()).await?;
+        let gas_price = u128::from(block.gas_price);
+        let used_gas = u128::from(block.fullness.0);
+        weighted_sum = weighted_sum.saturating_add(gas_price.saturating_mul(used_gas));
+        total_weight = total_weight.saturating_add(used_gas);
+        sum = sum.saturating_add(gas_price);
+        blocks = blocks.saturating_add(1);
+    }
+    let average = weighted_sum
+        .checked_div(total_weight)
+        .or_else(|| sum.checked_div(blocks))
+        .unwrap_or_default();
+    Ok(u64::try_from(average).unwrap_or(u64::MAX))
+}
+
+/// Rebuilds the metadata of the updater by replaying the fullness of the L2 blocks from
+/// `from` to `to` (inclusive) on top of the `starting` metadata, without re-executing
+/// the blocks. Useful to recover from corrupted metadata while the blocks are intact.
+///
+/// The `starting` metadata is expected to describe the state before the block `from`.
+pub async fn rebuild_gas_price_state<L2>(
+    l2_block_source: &L2,
+    starting: UpdaterMetadata,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> Result<UpdaterMetadata>
+where
+    L2: L2BlockSource,
+{
+    if from > to {
+        return Err(Error::EmptyBlockRange {
+            start: from,
+            end: to,
+        });
+    }
+    let mut updater: AlgorithmUpdaterV1 = starting.into();
+    updater.normalize();
+    // The updater expects the height of the last applied block
+    updater.l2_block_height = u32::from(from).saturating_sub(1);
+    for height in u32::from(from)..=u32::from(to) {
+        let BlockInfo {
+            height,
+            fullness,
+            block_bytes,
+            gas_price,
+            ..
+        } = l2_block_source.get_l2_block(height.into()).await?;
+        updater
+            .update_l2_block_data(height, fullness, block_bytes, gas_price)
+            .map_err(|err| Error::CouldNotReplayL2Block {
+                block_height: height.into(),
+                source_error: err.into(),
+            })?;
+    }
+    Ok(updater.into())
+}
+
 #[async_trait::async_trait]
 pub trait DARecordSource: Send + Sync {
     async fn get_da_record(&self) -> Result<Vec<RecordedBlock>>;
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum UpdaterMetadata {
     V1(AlgorithmUpdaterV1),
 }
 
+impl UpdaterMetadata {
+    /// The height of the last L2 block applied to the metadata.
+    pub fn l2_block_height(&self) -> BlockHeight {
+        match self {
+            UpdaterMetadata::V1(v1) => v1.l2_block_height.into(),
+        }
+    }
+}
+
 impl From<UpdaterMetadata> for AlgorithmUpdaterV1 {
     fn from(metadata: UpdaterMetadata) -> Self {
         match metadata {
@@ -73,6 +500,78 @@ impl From<AlgorithmUpdaterV1> for UpdaterMetadata {
 pub trait MetadataStorage: Send + Sync {
     async fn get_metadata(&self) -> Result<Option<UpdaterMetadata>>;
     async fn set_metadata(&self, metadata: UpdaterMetadata) -> Result<()>;
+
+    /// Returns the latest stored metadata together with its L2 block height, if that
+    /// height is at or below `height`.
+    ///
+    /// The storage only keeps the latest metadata, so `None` is returned for a `height`
+    /// below it rather than the metadata of an earlier block.
+    async fn get_metadata_at_or_before(
+        &self,
+        height: &BlockHeight,
+    ) -> Result<Option<(BlockHeight, UpdaterMetadata)>> {
+        let metadata = self.get_metadata().await?.and_then(|metadata| {
+            let metadata_height = metadata.l2_block_height();
+            (metadata_height <= *height).then_some((metadata_height, metadata))
+        });
+        Ok(metadata)
+    }
+}
+
+/// A [`MetadataStorage`] that retries the reads of the `inner` storage failing with a
+/// retryable error, doubling the backoff between the attempts up to `max_backoff`.
+/// Fatal errors and the error of the last attempt are returned as is.
+pub struct RetryingMetadataStorage<S> {
+    inner: S,
+    attempts: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl<S> RetryingMetadataStorage<S> {
+    /// Wraps `inner`, reading the metadata at most `attempts` times. A value of `0`
+    /// is treated as a single attempt.
+    pub fn new(
+        inner: S,
+        attempts: usize,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> Self {
+        Self {
+            inner,
+            attempts: attempts.max(1),
+            initial_backoff,
+            max_backoff,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> MetadataStorage for RetryingMetadataStorage<S>
+where
+    S: MetadataStorage,
+{
+    async fn get_metadata(&self) -> Result<Option<UpdaterMetadata>> {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            match self.inner.get_metadata().await {
+                Err(err) if err.is_retryable() && attempt < self.attempts => {
+                    tracing::warn!(
+                        "Failed to fetch the metadata on attempt {attempt}, retrying in {backoff:?}: {err}"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2).min(self.max_backoff);
+                    attempt = attempt.saturating_add(1);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn set_metadata(&self, metadata: UpdaterMetadata) -> Result<()> {
+        self.inner.set_metadata(metadata).await
+    }
 }
 
 impl<L2, Metadata> FuelGasPriceUpdater<L2, Metadata>
@@ -84,17 +583,29 @@ where
         l2_block_source: L2,
         metadata_storage: Metadata,
     ) -> Result<Self> {
-        let inner = metadata_storage
+        let mut inner: AlgorithmUpdaterV1 = metadata_storage
             .get_metadata()
             .await?
             .unwrap_or(init_metadata)
             .into();
-        let updater = Self {
-            inner,
+        inner.normalize();
+        let updater =
+            Self::with_strategy(Box::new(inner), l2_block_source, metadata_storage);
+        Ok(updater)
+    }
+
+    /// Creates the updater around a custom pricing `strategy`. The stored metadata is
+    /// not used to restore the strategy.
+    pub fn with_strategy(
+        strategy: Box<dyn GasPriceStrategy>,
+        l2_block_source: L2,
+        metadata_storage: Metadata,
+    ) -> Self {
+        Self {
+            inner: strategy,
             l2_block_source,
             metadata_storage,
-        };
-        Ok(updater)
+        }
     }
 }
 
@@ -104,15 +615,16 @@ where
     L2: L2BlockSource,
     Metadata: MetadataStorage + Send + Sync,
 {
-    type Algorithm = AlgorithmV1;
+    type Algorithm = Box<dyn GasPriceStrategy>;
 
     fn start(&self, _for_block: BlockHeight) -> Self::Algorithm {
-        self.inner.algorithm()
+        self.inner.boxed_clone()
     }
 
     async fn next(&mut self) -> anyhow::Result<Self::Algorithm> {
+        let next_height = self.inner.l2_block_height().saturating_add(1);
         tokio::select! {
-            l2_block = self.l2_block_source.get_l2_block(self.inner.l2_block_height.into()) => {
+            l2_block = self.l2_block_source.get_l2_block(next_height.into()) => {
                 tracing::info!("Received L2 block: {:?}", l2_block);
                 let l2_block = l2_block?;
                 let BlockInfo {
@@ -120,6 +632,7 @@ where
                     fullness,
                     block_bytes,
                     gas_price,
+                    ..
                 } = l2_block;
                 self.inner.update_l2_block_data(
                     height,
@@ -127,11 +640,53 @@ where
                     block_bytes,
                     gas_price,
                 )?;
-                self.metadata_storage
-                    .set_metadata(self.inner.clone().into())
-                    .await?;
-                Ok(self.inner.algorithm())
+                if let Some(metadata) = self.inner.metadata() {
+                    self.metadata_storage.set_metadata(metadata).await?;
+                }
+                Ok(self.inner.boxed_clone())
             }
         }
     }
 }
+
+fn record_metrics(
+    metrics: &GasPriceMetrics,
+    previous_exec_price: u64,
+    fullness: (u64, u64),
+    updater: &AlgorithmUpdaterV1,
+) {
+    let new_exec_price = updater.new_exec_price;
+    metrics
+        .exec_gas_price
+        .set(i64::try_from(new_exec_price).unwrap_or(i64::MAX));
+
+    let (used, capacity) = fullness;
+    let fullness_percent = used
+        .saturating_mul(100)
+        .checked_div(capacity)
+        .unwrap_or_default();
+    metrics
+        .last_fullness_percent
+        .set(i64::try_from(fullness_percent).unwrap_or(i64::MAX));
+
+    match new_exec_price.cmp(&previous_exec_price) {
+        std::cmp::Ordering::Greater => {
+            metrics.price_increases.inc();
+        }
+        std::cmp::Ordering::Less => {
+            metrics.price_decreases.inc();
+        }
+        std::cmp::Ordering::Equal => {}
+    }
+
+    if updater.last_update_clamped_to_min {
+        metrics.min_clamp_hits.inc();
+    }
+
+    metrics
+        .unrecorded_blocks
+        .set(i64::try_from(updater.unrecorded_block_count()).unwrap_or(i64::MAX));
+    metrics
+        .unrecorded_bytes
+        .set(i64::try_from(updater.unrecorded_bytes()).unwrap_or(i64::MAX));
+}
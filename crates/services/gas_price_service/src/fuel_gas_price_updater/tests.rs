@@ -1,7 +1,14 @@
 #![allow(non_snake_case)]
 
 use super::*;
-use std::sync::Arc;
+use fuel_core_types::fuel_tx::{
+    AssetId,
+    FeeParameters,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
 use tokio::sync::{
     mpsc::Receiver,
     Mutex,
@@ -59,7 +66,17 @@ fn arb_inner_updater() -> AlgorithmUpdaterV1 {
         new_exec_price: 100,
         // unset values
         l2_block_height: 0,
-        l2_block_fullness_threshold_percent: 0,
+        l2_block_fullness_threshold: (0, 100),
+        consecutive_low_blocks_required: 1,
+        consecutive_low_blocks: 0,
+        last_update_clamped_to_min: false,
+        surge_threshold_percent: 0,
+        surge_multiplier_percent: 100,
+        floor_decay_percent: 0,
+        floor_decay_after_blocks: 0,
+        min_floor: 0,
+        consecutive_clamped_blocks: 0,
+        strict_overflow: false,
         min_da_gas_price: 0,
         max_da_gas_price_change_percent: 0,
         total_da_rewards: 0,
@@ -84,7 +101,17 @@ fn different_inner_updater() -> AlgorithmUpdaterV1 {
         new_exec_price: 100,
         // unset values
         l2_block_height: 0,
-        l2_block_fullness_threshold_percent: 0,
+        l2_block_fullness_threshold: (0, 100),
+        consecutive_low_blocks_required: 1,
+        consecutive_low_blocks: 0,
+        last_update_clamped_to_min: false,
+        surge_threshold_percent: 0,
+        surge_multiplier_percent: 100,
+        floor_decay_percent: 0,
+        floor_decay_after_blocks: 0,
+        min_floor: 0,
+        consecutive_clamped_blocks: 0,
+        strict_overflow: false,
         min_da_gas_price: 0,
         max_da_gas_price_change_percent: 0,
         total_da_rewards: 0,
@@ -108,8 +135,12 @@ async fn next__fetches_l2_block() {
     let l2_block = BlockInfo {
         height: 1,
         fullness: (60, 100),
+        smoothed_fullness: (60, 100),
         block_bytes: 1000,
         gas_price: 200,
+        fee: 12_000,
+        block_gas_limit: 100,
+        fullness_source: FullnessSource::Fee,
     };
     let (l2_block_sender, l2_block_receiver) = tokio::sync::mpsc::channel(1);
     let l2_block_source = FakeL2BlockSource {
@@ -131,8 +162,7 @@ async fn next__fetches_l2_block() {
     let new = next.await.unwrap().unwrap();
 
     // then
-    dbg!(&start, &new);
-    assert_ne!(start, new);
+    assert_ne!(start.metadata(), new.metadata());
 }
 
 #[tokio::test]
@@ -156,11 +186,38 @@ async fn init__if_exists_already_reload() {
     .unwrap();
 
     // then
-    let expected = arb_inner_updater();
-    let actual = updater.inner;
+    let expected = Some(arb_inner_updater().into());
+    let actual = updater.inner.metadata();
     assert_eq!(expected, actual);
 }
 
+#[tokio::test]
+async fn init__exec_price_below_min_is_raised_to_min() {
+    // given
+    let metadata = AlgorithmUpdaterV1 {
+        new_exec_price: 10,
+        min_exec_gas_price: 100,
+        ..arb_inner_updater()
+    };
+    let metadata_storage = FakeMetadata {
+        inner: Arc::new(Mutex::new(Some(metadata.into()))),
+    };
+    let l2_block_source = PendingL2BlockSource;
+
+    // when
+    let updater = FuelGasPriceUpdater::init(
+        arb_inner_updater().into(),
+        l2_block_source,
+        metadata_storage,
+    )
+    .await
+    .unwrap();
+
+    // then
+    let price = updater.start(0.into()).calculate(0);
+    assert_eq!(price, 100);
+}
+
 #[tokio::test]
 async fn init__if_it_does_not_exist_create_with_provided_values() {
     // given
@@ -175,8 +232,8 @@ async fn init__if_it_does_not_exist_create_with_provided_values() {
             .unwrap();
 
     // then
-    let expected = different_inner_updater();
-    let actual = updater.inner;
+    let expected = Some(different_inner_updater().into());
+    let actual = updater.inner.metadata();
     assert_eq!(expected, actual);
 }
 
@@ -186,8 +243,12 @@ async fn next__new_l2_block_updates_metadata() {
     let l2_block = BlockInfo {
         height: 1,
         fullness: (60, 100),
+        smoothed_fullness: (60, 100),
         block_bytes: 1000,
         gas_price: 200,
+        fee: 12_000,
+        block_gas_limit: 100,
+        fullness_source: FullnessSource::Fee,
     };
     let (l2_block_sender, l2_block_receiver) = tokio::sync::mpsc::channel(1);
     let l2_block_source = FakeL2BlockSource {
@@ -226,3 +287,751 @@ async fn next__new_l2_block_updates_metadata() {
     let actual = metadata_inner.lock().await.clone().unwrap().into();
     assert_eq!(expected, actual);
 }
+
+#[tokio::test]
+async fn init__restarted_updater_continues_from_stored_metadata() {
+    // given
+    let block = |height| BlockInfo {
+        height,
+        fullness: (100, 100),
+        smoothed_fullness: (100, 100),
+        block_bytes: 1000,
+        gas_price: 200,
+        fee: 20_000,
+        block_gas_limit: 100,
+        fullness_source: FullnessSource::Fee,
+    };
+    let metadata_inner = Arc::new(Mutex::new(None));
+    let (l2_block_sender, l2_block_receiver) = tokio::sync::mpsc::channel(2);
+    let mut updater = FuelGasPriceUpdater::init(
+        arb_inner_updater().into(),
+        FakeL2BlockSource {
+            l2_block: Arc::new(Mutex::new(l2_block_receiver)),
+        },
+        FakeMetadata {
+            inner: metadata_inner.clone(),
+        },
+    )
+    .await
+    .unwrap();
+    l2_block_sender.send(block(1)).await.unwrap();
+    l2_block_sender.send(block(2)).await.unwrap();
+    updater.next().await.unwrap();
+    let before_restart = updater.next().await.unwrap();
+    drop(updater);
+
+    // when
+    let (l2_block_sender, l2_block_receiver) = tokio::sync::mpsc::channel(1);
+    let mut restarted = FuelGasPriceUpdater::init(
+        different_inner_updater().into(),
+        FakeL2BlockSource {
+            l2_block: Arc::new(Mutex::new(l2_block_receiver)),
+        },
+        FakeMetadata {
+            inner: metadata_inner.clone(),
+        },
+    )
+    .await
+    .unwrap();
+    let after_restart = restarted.start(2.into());
+    l2_block_sender.send(block(3)).await.unwrap();
+    let next = restarted.next().await.unwrap();
+
+    // then
+    assert_eq!(after_restart.metadata(), before_restart.metadata());
+    assert_eq!(next.l2_block_height(), 3);
+    let mut expected: AlgorithmUpdaterV1 = before_restart.metadata().unwrap().into();
+    expected
+        .update_l2_block_data(3, (100, 100), 1000, 200)
+        .unwrap();
+    assert_eq!(next.metadata(), Some(expected.into()));
+}
+
+#[derive(Clone)]
+struct FixedPriceStrategy {
+    price: u64,
+    l2_block_height: u32,
+}
+
+impl GasPriceStrategy for FixedPriceStrategy {
+    fn update_l2_block_data(
+        &mut self,
+        height: u32,
+        _fullness: (u64, u64),
+        _block_bytes: u64,
+        _gas_price: u64,
+    ) -> anyhow::Result<()> {
+        self.l2_block_height = height;
+        Ok(())
+    }
+
+    fn calculate(&self, _block_bytes: u64) -> u64 {
+        self.price
+    }
+
+    fn l2_block_height(&self) -> u32 {
+        self.l2_block_height
+    }
+
+    fn metadata(&self) -> Option<UpdaterMetadata> {
+        None
+    }
+
+    fn boxed_clone(&self) -> Box<dyn GasPriceStrategy> {
+        Box::new(self.clone())
+    }
+}
+
+#[tokio::test]
+async fn next__custom_strategy_prices_the_next_block() {
+    // given
+    let l2_block = BlockInfo {
+        height: 1,
+        fullness: (100, 100),
+        smoothed_fullness: (100, 100),
+        block_bytes: 1000,
+        gas_price: 200,
+        fee: 20_000,
+        block_gas_limit: 100,
+        fullness_source: FullnessSource::Fee,
+    };
+    let (l2_block_sender, l2_block_receiver) = tokio::sync::mpsc::channel(1);
+    let l2_block_source = FakeL2BlockSource {
+        l2_block: Arc::new(Mutex::new(l2_block_receiver)),
+    };
+    let metadata_inner = Arc::new(Mutex::new(None));
+    let metadata_storage = FakeMetadata {
+        inner: metadata_inner.clone(),
+    };
+    let strategy = FixedPriceStrategy {
+        price: 42,
+        l2_block_height: 0,
+    };
+    let mut updater = FuelGasPriceUpdater::with_strategy(
+        Box::new(strategy),
+        l2_block_source,
+        metadata_storage,
+    );
+
+    // when
+    let next = tokio::spawn(async move { updater.next().await });
+    l2_block_sender.send(l2_block).await.unwrap();
+    let new = next.await.unwrap().unwrap();
+
+    // then
+    assert_eq!(new.calculate(0), 42);
+    assert_eq!(new.calculate(1_000_000), 42);
+    assert_eq!(new.l2_block_height(), 1);
+    assert!(metadata_inner.lock().await.is_none());
+}
+
+#[test]
+fn record_metrics__counts_the_direction_of_each_price_change() {
+    // given
+    let metrics = GasPriceMetrics::default();
+    let mut updater = AlgorithmUpdaterV1 {
+        l2_block_fullness_threshold: (50, 100),
+        min_exec_gas_price: 100,
+        ..arb_inner_updater()
+    };
+    let fullness_per_block = [(60, 100), (60, 100), (40, 100), (0, 100), (0, 100)];
+
+    // when
+    for (fullness, height) in fullness_per_block.into_iter().zip(1u32..) {
+        let previous_exec_price = updater.new_exec_price;
+        updater
+            .update_l2_block_data(height, fullness, 1000, 200)
+            .unwrap();
+        record_metrics(&metrics, previous_exec_price, fullness, &updater);
+    }
+
+    // then
+    assert_eq!(metrics.price_increases.get(), 2);
+    assert_eq!(metrics.price_decreases.get(), 2);
+    assert_eq!(metrics.min_clamp_hits.get(), 2);
+    assert_eq!(metrics.exec_gas_price.get(), 100);
+    assert_eq!(metrics.last_fullness_percent.get(), 0);
+    assert_eq!(metrics.unrecorded_blocks.get(), 5);
+    assert_eq!(metrics.unrecorded_bytes.get(), 5000);
+}
+
+fn block_with_mint(height: u32, fee: u64, gas_price: u64) -> Block<Transaction> {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height.into());
+    let mint = Transaction::mint(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        fee,
+        AssetId::BASE,
+        gas_price,
+    );
+    block.transactions_mut().push(mint.into());
+    block
+}
+
+fn capacity(gas_price_factor: u64, gas_limit: u64) -> BlockCapacity {
+    BlockCapacity {
+        gas_limit,
+        gas_price_factor,
+        exclude_mint: false,
+    }
+}
+
+#[test]
+fn block_capacity__is_taken_from_consensus_parameters() {
+    // given
+    let mut params = ConsensusParameters::default();
+    params.set_block_gas_limit(1_000);
+    params.set_fee_params(FeeParameters::default().with_gas_price_factor(100));
+
+    // when
+    let capacity = BlockCapacity::from_consensus_parameters(&params);
+
+    // then
+    assert_eq!(capacity.gas_limit, 1_000);
+    assert_eq!(capacity.gas_price_factor, 100);
+    assert!(!capacity.exclude_mint);
+}
+
+struct FakeConsensusParams {
+    params: HashMap<ConsensusParametersVersion, ConsensusParameters>,
+}
+
+impl ConsensusParamsProvider for FakeConsensusParams {
+    fn get_params(
+        &self,
+        version: ConsensusParametersVersion,
+    ) -> Option<ConsensusParameters> {
+        self.params.get(&version).cloned()
+    }
+}
+
+#[test]
+fn block_capacity_for_block__uses_the_params_of_the_block_version() {
+    // given
+    let mut params = ConsensusParameters::default();
+    params.set_block_gas_limit(2_000);
+    params.set_fee_params(FeeParameters::default().with_gas_price_factor(50));
+    let provider = FakeConsensusParams {
+        params: HashMap::from([(1, params)]),
+    };
+    let mut block = block_with_mint(1, 300, 50);
+    block
+        .header_mut()
+        .application_mut()
+        .consensus_parameters_version = 1;
+
+    // when
+    let capacity = BlockCapacity::for_block(&block, &provider).unwrap();
+
+    // then
+    assert_eq!(capacity.gas_limit, 2_000);
+    assert_eq!(capacity.gas_price_factor, 50);
+}
+
+#[test]
+fn block_capacity_for_block__unknown_version_is_an_error() {
+    // given
+    let provider = FakeConsensusParams {
+        params: HashMap::new(),
+    };
+    let block = block_with_mint(1, 300, 50);
+
+    // when
+    let result = BlockCapacity::for_block(&block, &provider);
+
+    // then
+    assert!(matches!(result, Err(Error::CouldNotFetchL2Block { .. })));
+}
+
+#[test]
+fn get_block_info__calculates_fullness_from_mint() {
+    // given
+    let gas_price_factor = 100;
+    let block_gas_limit = 1_000;
+    let used_gas = 600;
+    let gas_price = 50;
+    // used_gas * gas_price / gas_price_factor
+    let fee = 300;
+    let block = block_with_mint(1, fee, gas_price);
+
+    // when
+    let info =
+        get_block_info(&block, &capacity(gas_price_factor, block_gas_limit)).unwrap();
+
+    // then
+    assert_eq!(info.height, 1);
+    assert_eq!(info.fullness, (used_gas, block_gas_limit));
+    assert_eq!(info.fee, fee);
+    assert_eq!(info.gas_price, gas_price);
+    assert_eq!(info.block_gas_limit, block_gas_limit);
+    assert_eq!(info.fullness_source, FullnessSource::Fee);
+    assert!(info.block_bytes > 0);
+}
+
+#[test]
+fn get_block_info__exclude_mint_leaves_the_mint_out_of_the_block_bytes() {
+    // given
+    let mut block = block_with_mint(1, 300, 50);
+    let user_tx = Transaction::Script(Default::default());
+    let user_tx_bytes = u64::try_from(user_tx.size()).unwrap();
+    block.transactions_mut().insert(0, user_tx);
+    let with_mint = capacity(100, 1_000);
+    let without_mint = BlockCapacity {
+        exclude_mint: true,
+        ..with_mint
+    };
+
+    // when
+    let info_with_mint = get_block_info(&block, &with_mint).unwrap();
+    let info_without_mint = get_block_info(&block, &without_mint).unwrap();
+
+    // then
+    assert_eq!(info_without_mint.block_bytes, user_tx_bytes);
+    assert!(info_with_mint.block_bytes > info_without_mint.block_bytes);
+    assert_eq!(info_with_mint.fullness, info_without_mint.fullness);
+}
+
+#[test]
+fn get_block_info_with_gas_used__prefers_the_gas_used() {
+    // given
+    let block_gas_limit = 1_000;
+    let gas_used = 450;
+    // would be derived as 600 from the fee
+    let block = block_with_mint(1, 300, 50);
+
+    // when
+    let info = get_block_info_with_gas_used(
+        &block,
+        Some(gas_used),
+        &capacity(100, block_gas_limit),
+    )
+    .unwrap();
+
+    // then
+    assert_eq!(info.fullness, (gas_used, block_gas_limit));
+    assert_eq!(info.fullness_source, FullnessSource::GasUsed);
+}
+
+#[test]
+fn get_block_info_with_gas_used__zero_gas_price_uses_the_gas_used() {
+    // given
+    let block_gas_limit = 1_000;
+    let block = block_with_mint(1, 0, 0);
+
+    // when
+    let info =
+        get_block_info_with_gas_used(&block, Some(200), &capacity(100, block_gas_limit))
+            .unwrap();
+
+    // then
+    assert_eq!(info.fullness, (200, block_gas_limit));
+    assert_eq!(info.fullness_source, FullnessSource::GasUsed);
+}
+
+#[test]
+fn block_gas_used__sums_successful_and_failed_transactions() {
+    // given
+    let status = |result| TransactionExecutionStatus {
+        id: Default::default(),
+        result,
+    };
+    let statuses = vec![
+        status(TransactionExecutionResult::Success {
+            result: None,
+            receipts: vec![],
+            total_gas: 100,
+            total_fee: 0,
+        }),
+        status(TransactionExecutionResult::Failed {
+            result: None,
+            receipts: vec![],
+            total_gas: 50,
+            total_fee: 0,
+        }),
+    ];
+
+    // when
+    let gas_used = block_gas_used(&statuses);
+
+    // then
+    assert_eq!(gas_used, 150);
+}
+
+#[test]
+fn get_block_info__zero_fee_is_an_empty_block() {
+    // given
+    let block_gas_limit = 1_000;
+    let block = block_with_mint(1, 0, 50);
+
+    // when
+    let info = get_block_info(&block, &capacity(100, block_gas_limit)).unwrap();
+
+    // then
+    assert_eq!(info.fullness, (0, block_gas_limit));
+}
+
+#[test]
+fn get_block_info__zero_gas_price_does_not_panic() {
+    // given
+    let block_gas_limit = 1_000;
+    let block = block_with_mint(1, 300, 0);
+
+    // when
+    let info = get_block_info(&block, &capacity(100, block_gas_limit)).unwrap();
+
+    // then
+    assert_eq!(info.gas_price, 0);
+    assert_eq!(info.fullness, (0, block_gas_limit));
+}
+
+#[test]
+fn get_block_info__block_without_mint_is_an_error() {
+    // given
+    let block = Block::default();
+
+    // when
+    let result = get_block_info(&block, &capacity(100, 1_000));
+
+    // then
+    assert!(matches!(result, Err(Error::CouldNotFetchL2Block { .. })));
+}
+
+struct FlakyMetadata {
+    failures: Arc<Mutex<Vec<Error>>>,
+    reads: Arc<Mutex<usize>>,
+    metadata: UpdaterMetadata,
+}
+
+impl FlakyMetadata {
+    fn failing_with(failures: Vec<Error>) -> Self {
+        Self {
+            failures: Arc::new(Mutex::new(failures)),
+            reads: Arc::new(Mutex::new(0)),
+            metadata: arb_inner_updater().into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MetadataStorage for FlakyMetadata {
+    async fn get_metadata(&self) -> Result<Option<UpdaterMetadata>> {
+        let mut reads = self.reads.lock().await;
+        *reads = reads.saturating_add(1);
+        drop(reads);
+        match self.failures.lock().await.pop() {
+            Some(err) => Err(err),
+            None => Ok(Some(self.metadata.clone())),
+        }
+    }
+
+    async fn set_metadata(&self, _metadata: UpdaterMetadata) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn metadata_error(retryable: bool) -> Error {
+    Error::CouldNotFetchMetadata {
+        source_error: anyhow!("backend is busy"),
+        retryable,
+    }
+}
+
+fn retrying(
+    inner: FlakyMetadata,
+    attempts: usize,
+) -> RetryingMetadataStorage<FlakyMetadata> {
+    RetryingMetadataStorage::new(
+        inner,
+        attempts,
+        Duration::from_millis(1),
+        Duration::from_millis(4),
+    )
+}
+
+#[tokio::test]
+async fn retrying_metadata_storage__returns_the_value_after_a_transient_failure() {
+    // given
+    let inner = FlakyMetadata::failing_with(vec![metadata_error(true)]);
+    let reads = inner.reads.clone();
+    let storage = retrying(inner, 3);
+
+    // when
+    let metadata = storage.get_metadata().await.unwrap();
+
+    // then
+    assert_eq!(metadata, Some(arb_inner_updater().into()));
+    assert_eq!(*reads.lock().await, 2);
+}
+
+#[tokio::test]
+async fn retrying_metadata_storage__does_not_retry_fatal_errors() {
+    // given
+    let inner = FlakyMetadata::failing_with(vec![metadata_error(false)]);
+    let reads = inner.reads.clone();
+    let storage = retrying(inner, 3);
+
+    // when
+    let result = storage.get_metadata().await;
+
+    // then
+    assert!(matches!(
+        result,
+        Err(Error::CouldNotFetchMetadata {
+            retryable: false,
+            ..
+        })
+    ));
+    assert_eq!(*reads.lock().await, 1);
+}
+
+#[tokio::test]
+async fn retrying_metadata_storage__gives_up_after_the_last_attempt() {
+    // given
+    let failures = std::iter::repeat_with(|| metadata_error(true))
+        .take(5)
+        .collect();
+    let inner = FlakyMetadata::failing_with(failures);
+    let reads = inner.reads.clone();
+    let storage = retrying(inner, 3);
+
+    // when
+    let result = storage.get_metadata().await;
+
+    // then
+    assert!(result.unwrap_err().is_retryable());
+    assert_eq!(*reads.lock().await, 3);
+}
+
+struct FixedL2BlockSource {
+    blocks: Vec<BlockInfo>,
+}
+
+#[async_trait::async_trait]
+impl L2BlockSource for FixedL2BlockSource {
+    async fn get_l2_block(&self, height: BlockHeight) -> Result<BlockInfo> {
+        self.blocks
+            .iter()
+            .find(|block| block.height == u32::from(height))
+            .cloned()
+            .ok_or_else(|| Error::CouldNotFetchL2Block {
+                block_height: height,
+                source_error: anyhow!("No block at {height}"),
+            })
+    }
+}
+
+fn block_with_price(height: u32, used_gas: u64, gas_price: u64) -> BlockInfo {
+    BlockInfo {
+        height,
+        fullness: (used_gas, 100),
+        smoothed_fullness: (used_gas, 100),
+        block_bytes: 1000,
+        gas_price,
+        fee: 0,
+        block_gas_limit: 100,
+        fullness_source: FullnessSource::GasUsed,
+    }
+}
+
+#[tokio::test]
+async fn average_gas_price__weights_the_prices_by_the_used_gas() {
+    // given
+    let source = FixedL2BlockSource {
+        blocks: vec![
+            block_with_price(1, 10, 100),
+            block_with_price(2, 30, 200),
+            block_with_price(3, 60, 300),
+            block_with_price(4, 100, 1000),
+        ],
+    };
+
+    // when
+    let average = average_gas_price(&source, 1.into(), 3.into())
+        .await
+        .unwrap();
+
+    // then
+    // (10 * 100 + 30 * 200 + 60 * 300) / (10 + 30 + 60)
+    assert_eq!(average, 250);
+}
+
+#[tokio::test]
+async fn average_gas_price__empty_blocks_are_weighted_equally() {
+    // given
+    let source = FixedL2BlockSource {
+        blocks: vec![block_with_price(1, 0, 100), block_with_price(2, 0, 200)],
+    };
+
+    // when
+    let average = average_gas_price(&source, 1.into(), 2.into())
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(average, 150);
+}
+
+#[tokio::test]
+async fn average_gas_price__rejects_an_empty_range() {
+    // given
+    let source = FixedL2BlockSource { blocks: vec![] };
+
+    // when
+    let result = average_gas_price(&source, 2.into(), 1.into()).await;
+
+    // then
+    assert!(matches!(result, Err(Error::EmptyBlockRange { .. })));
+}
+
+#[tokio::test]
+async fn rebuild_gas_price_state__matches_the_live_accumulated_state() {
+    // given
+    let blocks = vec![
+        block_with_price(1, 100, 100),
+        block_with_price(2, 0, 110),
+        block_with_price(3, 80, 99),
+        block_with_price(4, 20, 108),
+    ];
+    let starting = AlgorithmUpdaterV1 {
+        l2_block_height: 0,
+        l2_block_fullness_threshold: (50, 100),
+        ..arb_inner_updater()
+    };
+    let metadata_inner = Arc::new(Mutex::new(None));
+    let metadata_storage = FakeMetadata {
+        inner: metadata_inner.clone(),
+    };
+    let mut live = FuelGasPriceUpdater::init(
+        starting.clone().into(),
+        FixedL2BlockSource {
+            blocks: blocks.clone(),
+        },
+        metadata_storage,
+    )
+    .await
+    .unwrap();
+    for _ in 0..blocks.len() {
+        live.next().await.unwrap();
+    }
+    let live_metadata = metadata_inner.lock().await.clone().unwrap();
+
+    // when
+    let rebuilt = rebuild_gas_price_state(
+        &FixedL2BlockSource { blocks },
+        starting.into(),
+        1.into(),
+        4.into(),
+    )
+    .await
+    .unwrap();
+
+    // then
+    assert_eq!(rebuilt, live_metadata);
+}
+
+#[tokio::test]
+async fn rebuild_gas_price_state__fails_on_a_missing_block() {
+    // given
+    let source = FixedL2BlockSource {
+        blocks: vec![block_with_price(1, 10, 100)],
+    };
+    let starting = AlgorithmUpdaterV1 {
+        l2_block_height: 0,
+        ..arb_inner_updater()
+    };
+
+    // when
+    let result =
+        rebuild_gas_price_state(&source, starting.into(), 1.into(), 2.into()).await;
+
+    // then
+    assert!(matches!(result, Err(Error::CouldNotFetchL2Block { .. })));
+}
+
+#[tokio::test]
+async fn get_metadata_at_or_before__returns_the_latest_metadata_unless_it_is_above_the_height(
+) {
+    // given
+    let metadata: UpdaterMetadata = AlgorithmUpdaterV1 {
+        l2_block_height: 6,
+        ..arb_inner_updater()
+    }
+    .into();
+    let metadata_storage = FakeMetadata {
+        inner: Arc::new(Mutex::new(Some(metadata.clone()))),
+    };
+
+    // when
+    let at_height = metadata_storage
+        .get_metadata_at_or_before(&6.into())
+        .await
+        .unwrap();
+    let above_height = metadata_storage
+        .get_metadata_at_or_before(&9.into())
+        .await
+        .unwrap();
+    let below_height = metadata_storage
+        .get_metadata_at_or_before(&5.into())
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(at_height, Some((6.into(), metadata.clone())));
+    assert_eq!(above_height, Some((6.into(), metadata)));
+    assert_eq!(below_height, None);
+}
+
+#[tokio::test]
+async fn get_metadata_at_or_before__returns_none_without_metadata() {
+    // given
+    let metadata_storage = FakeMetadata::empty();
+
+    // when
+    let result = metadata_storage
+        .get_metadata_at_or_before(&5.into())
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(result, None);
+}
+
+#[tokio::test]
+async fn smoothed_l2_block_source__smoothed_fullness_lags_the_spikes() {
+    // given
+    let raw_used_gas = [0, 0, 90, 0, 0, 0];
+    let source = SmoothedL2BlockSource::new(
+        FixedL2BlockSource {
+            blocks: raw_used_gas
+                .iter()
+                .zip(1u32..)
+                .map(|(used_gas, height)| block_with_price(height, *used_gas, 100))
+                .collect(),
+        },
+        3,
+    );
+
+    // when
+    let mut blocks = vec![];
+    for height in 1u32..=6 {
+        blocks.push(source.get_l2_block(height.into()).await.unwrap());
+    }
+
+    // then
+    let raw: Vec<_> = blocks.iter().map(|block| block.fullness.0).collect();
+    let smoothed: Vec<_> = blocks.iter().map(|block| block.smoothed_fullness).collect();
+    assert_eq!(raw, raw_used_gas);
+    assert_eq!(
+        smoothed,
+        vec![
+            (0, 100),
+            (0, 100),
+            (30, 100),
+            (30, 100),
+            (30, 100),
+            (0, 100)
+        ]
+    );
+}
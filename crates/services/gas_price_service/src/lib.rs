@@ -75,6 +75,9 @@ pub trait GasPriceAlgorithm {
     fn last_gas_price(&self) -> u64;
     fn next_gas_price(&self, block_bytes: u64) -> u64;
     fn worst_case_gas_price(&self, block_height: BlockHeight) -> u64;
+    /// The gas price expected after `blocks_ahead` more blocks, each filled to the
+    /// `(used, capacity)` fullness.
+    fn projected_gas_price(&self, blocks_ahead: u32, fullness: (u64, u64)) -> u64;
 }
 
 impl<A, U> GasPriceService<A, U>
@@ -124,6 +127,17 @@ where
     pub async fn worst_case_gas_price(&self, block_height: BlockHeight) -> u64 {
         self.0.read().await.worst_case_gas_price(block_height)
     }
+
+    pub async fn projected_gas_price(
+        &self,
+        blocks_ahead: u32,
+        fullness: (u64, u64),
+    ) -> u64 {
+        self.0
+            .read()
+            .await
+            .projected_gas_price(blocks_ahead, fullness)
+    }
 }
 
 #[async_trait]
@@ -214,6 +228,10 @@ mod tests {
         fn worst_case_gas_price(&self, _block_height: BlockHeight) -> u64 {
             self.price
         }
+
+        fn projected_gas_price(&self, _blocks_ahead: u32, _fullness: (u64, u64)) -> u64 {
+            self.price
+        }
     }
 
     struct TestAlgorithmUpdater {
@@ -41,6 +41,10 @@ impl GasPriceAlgorithm for StaticAlgorithm {
     fn worst_case_gas_price(&self, _block_height: BlockHeight) -> u64 {
         self.price()
     }
+
+    fn projected_gas_price(&self, _blocks_ahead: u32, _fullness: (u64, u64)) -> u64 {
+        self.price()
+    }
 }
 #[async_trait]
 impl UpdateAlgorithm for StaticAlgorithmUpdater {
@@ -9,6 +9,7 @@ use crate::{
     },
     kv_store::{
         KVItem,
+        KeyItem,
         KeyValueInspect,
     },
     structured_storage::TableWithBlueprint,
@@ -77,6 +78,21 @@ pub trait IterableStore: KeyValueInspect {
         start: Option<&[u8]>,
         direction: IterDirection,
     ) -> BoxedIter<KVItem>;
+
+    /// Returns an iterator over the keys in the storage. The default implementation
+    /// reads the values and drops them, stores that can skip reading the values
+    /// should override it.
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.iter_store(column, prefix, start, direction)
+            .map(|item| item.map(|(key, _)| key))
+            .into_boxed()
+    }
 }
 
 /// A trait for iterating over the `Mappable` table.
@@ -10,6 +10,8 @@ use std::sync::Arc;
 pub type Value = Arc<Vec<u8>>;
 /// The pair of key and value from the storage.
 pub type KVItem = StorageResult<(Vec<u8>, Value)>;
+/// The key from the storage.
+pub type KeyItem = StorageResult<Vec<u8>>;
 
 /// A column of the storage.
 pub trait StorageColumn: Copy + core::fmt::Debug {
@@ -21,6 +21,7 @@ use crate::{
     kv_store::{
         BatchOperations,
         KVItem,
+        KeyItem,
         KeyValueInspect,
         KeyValueMutate,
         StorageColumn,
@@ -200,6 +201,16 @@ where
     ) -> BoxedIter<KVItem> {
         self.inner.iter_store(column, prefix, start, direction)
     }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.inner.iter_store_keys(column, prefix, start, direction)
+    }
 }
 
 impl<S> Modifiable for StructuredStorage<S>
@@ -97,6 +97,7 @@ pub struct TestSetupBuilder {
     pub privileged_address: Address,
     pub base_asset_id: AssetId,
     pub trigger: Trigger,
+    pub test_block_gas_limit: Option<u64>,
 }
 
 impl TestSetupBuilder {
@@ -227,6 +228,7 @@ impl TestSetupBuilder {
             txpool: fuel_core_txpool::Config::default(),
             block_production: self.trigger,
             static_gas_price: self.min_gas_price,
+            test_block_gas_limit: self.test_block_gas_limit,
             ..Config::local_node_with_configs(chain_conf, state)
         };
 
@@ -254,6 +256,7 @@ impl Default for TestSetupBuilder {
             privileged_address: Default::default(),
             base_asset_id: AssetId::BASE,
             trigger: Trigger::Instant,
+            test_block_gas_limit: None,
         }
     }
 }
@@ -323,6 +323,29 @@ async fn block_connection_5(
     };
 }
 
+#[tokio::test]
+async fn blocks__page_larger_than_max_page_size_returns_an_error() {
+    let mut config = Config::local_node();
+    config.graphql_config.max_page_size = 10;
+    let srv = FuelService::from_database(Default::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client
+        .blocks(PaginationRequest {
+            cursor: None,
+            results: 11,
+            direction: PageDirection::Forward,
+        })
+        .await;
+
+    let err = result.expect_err("The page should exceed the max page size");
+    assert!(err
+        .to_string()
+        .contains("The page size `11` exceeds the max page size `10`"));
+}
+
 #[tokio::test]
 async fn missing_first_and_last_parameters_returns_an_error() {
     let query = r#"
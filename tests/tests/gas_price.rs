@@ -133,6 +133,70 @@ async fn latest_gas_price__if_no_mint_tx_in_previous_block_gas_price_is_zero() {
     assert_eq!(expected, actual)
 }
 
+#[tokio::test]
+async fn current_gas_price__matches_the_gas_price_of_the_latest_block() {
+    // given
+    let mut node_config = Config::local_node();
+    node_config.static_gas_price = 100;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // when
+    let actual = srv.current_gas_price().await;
+
+    // then
+    let expected = srv.gas_price_at_block(3.into()).unwrap().unwrap();
+    assert_eq!(expected, actual);
+}
+
+#[tokio::test]
+async fn estimate_future_gas_price__matches_the_price_of_later_blocks() {
+    // given
+    let mut node_config = Config::local_node();
+    node_config.static_gas_price = 100;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    // Empty blocks, so the recent fullness is known to be zero
+    client.produce_blocks(3, None).await.unwrap();
+
+    // when
+    let estimate = srv.estimate_future_gas_price(1).await;
+
+    // then
+    client.produce_blocks(1, None).await.unwrap();
+    let actual = srv.gas_price_at_block(4.into()).unwrap().unwrap();
+    assert_eq!(estimate, actual);
+}
+
+#[tokio::test]
+async fn gas_price_at_block__returns_the_price_each_block_was_produced_with() {
+    // given
+    let mut node_config = Config::local_node();
+    node_config.static_gas_price = 100;
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // when
+    let prices: Vec<_> = (0u32..=4)
+        .map(|height| srv.gas_price_at_block(height.into()).unwrap())
+        .collect();
+
+    // then
+    let static_gas_price = node_config.static_gas_price;
+    assert_eq!(
+        prices,
+        vec![
+            Some(0),
+            Some(static_gas_price),
+            Some(static_gas_price),
+            Some(static_gas_price),
+            None,
+        ]
+    );
+}
+
 #[tokio::test]
 async fn estimate_gas_price__should_be_static() {
     // given
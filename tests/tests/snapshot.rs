@@ -1,18 +1,37 @@
 use fuel_core::{
     chain_config::{
+        ChainConfig,
+        CoinConfig,
         LastBlockConfig,
         Randomize,
+        SnapshotMetadata,
+        SnapshotReader,
+        SnapshotWriter,
         StateConfig,
+        MAX_GROUP_SIZE,
     },
     combined_database::CombinedDatabase,
     service::{
+        genesis::{
+            Exporter,
+            NotifyCancel,
+            SnapshotImporter,
+        },
         Config,
         FuelService,
+        ServiceTrait,
     },
 };
 use fuel_core_poa::ports::Database;
-use fuel_core_storage::transactional::AtomicView;
-use fuel_core_types::blockchain::primitives::DaBlockHeight;
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::Coins,
+    transactional::AtomicView,
+};
+use fuel_core_types::blockchain::{
+    block::Block,
+    primitives::DaBlockHeight,
+};
 use rand::{
     rngs::StdRng,
     Rng,
@@ -61,3 +80,158 @@ async fn loads_snapshot() {
     // initial state
     pretty_assertions::assert_eq!(expected, actual_state);
 }
+
+struct NeverCancel;
+
+#[async_trait::async_trait]
+impl NotifyCancel for NeverCancel {
+    async fn wait_until_cancelled(&self) -> anyhow::Result<()> {
+        std::future::pending().await
+    }
+
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn exported_snapshot_round_trips_through_import() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let original_db = CombinedDatabase::default();
+
+    // setup original node
+    let starting_state = StateConfig {
+        last_block: Some(LastBlockConfig {
+            block_height: (u32::MAX - 2).into(),
+            da_block_height: DaBlockHeight(u64::MAX),
+            consensus_parameters_version: u32::MAX - 2,
+            state_transition_version: u32::MAX - 2,
+            blocks_root: rng.gen(),
+        }),
+        ..StateConfig::randomize(&mut rng)
+    };
+    let config = Config::local_node_with_state_config(starting_state);
+    let _original = FuelService::from_combined_database(original_db.clone(), config)
+        .await
+        .unwrap();
+
+    // export the original state
+    let snapshot_dir = tempfile::tempdir().unwrap();
+    let output_dir = snapshot_dir.path().to_path_buf();
+    Exporter::new(
+        original_db.clone(),
+        ChainConfig::local_testnet(),
+        move || Ok(SnapshotWriter::json(output_dir.clone())),
+        MAX_GROUP_SIZE,
+        NeverCancel,
+    )
+    .write_full_snapshot()
+    .await
+    .unwrap();
+
+    // import the snapshot into a fresh node
+    let metadata = SnapshotMetadata::read(snapshot_dir.path()).unwrap();
+    let reader = SnapshotReader::open(metadata).unwrap();
+    let imported_db = CombinedDatabase::default();
+    let _imported = FuelService::from_combined_database(
+        imported_db.clone(),
+        Config::local_node_with_reader(reader),
+    )
+    .await
+    .unwrap();
+
+    // then
+    let original_state = original_db.read_state_config().unwrap();
+    let imported_state = imported_db.read_state_config().unwrap();
+    let original_height = original_state.last_block.unwrap().block_height;
+    let imported_height = imported_state.last_block.unwrap().block_height;
+    assert_eq!(imported_height, original_height.succ().unwrap());
+    pretty_assertions::assert_eq!(
+        StateConfig {
+            last_block: None,
+            ..original_state
+        },
+        StateConfig {
+            last_block: None,
+            ..imported_state
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_status_advances_to_complete_during_startup() {
+    // given
+    let mut rng = StdRng::seed_from_u64(1234);
+    let coins = std::iter::repeat_with(|| CoinConfig {
+        tx_pointer_block_height: 0.into(),
+        ..Randomize::randomize(&mut rng)
+    })
+    .take(2_000)
+    .collect();
+    let state = StateConfig {
+        coins,
+        ..StateConfig::randomize(&mut rng)
+    };
+    let snapshot_dir = tempfile::tempdir().unwrap();
+    let metadata = SnapshotWriter::json(snapshot_dir.path())
+        .write_state_config(state, &ChainConfig::local_testnet())
+        .unwrap();
+    // Small groups, so that the coins are imported by a parallel worker for long
+    // enough to be observed.
+    let reader = SnapshotReader::open_w_config(metadata, 10).unwrap();
+    let config = Config::local_node_with_reader(reader);
+    let service = FuelService::new(CombinedDatabase::default(), config).unwrap();
+    assert!(!service.import_status().in_progress);
+
+    // when
+    service.start().unwrap();
+    let mut observed = vec![];
+    while !service.state().started() {
+        assert!(!service.state().stopped(), "The service failed to start");
+        observed.push(service.import_status());
+        tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+    }
+
+    // then
+    assert!(
+        observed.iter().any(|status| status.in_progress),
+        "The import was never observed in progress"
+    );
+    assert!(observed
+        .windows(2)
+        .all(|pair| pair[0].tables_done <= pair[1].tables_done));
+    let status = service.import_status();
+    assert!(!status.in_progress);
+    assert!(status.tables_total > 0);
+    assert_eq!(status.tables_done, status.tables_total);
+    assert!(status.rows_applied > 0);
+}
+
+#[tokio::test]
+async fn import_to_memory__imports_the_snapshot_without_a_node() {
+    // given
+    let mut rng = StdRng::seed_from_u64(1234);
+    let coins: Vec<_> = std::iter::repeat_with(|| CoinConfig {
+        tx_pointer_block_height: 0.into(),
+        ..Randomize::randomize(&mut rng)
+    })
+    .take(10)
+    .collect();
+    let state = StateConfig {
+        coins: coins.clone(),
+        ..Default::default()
+    };
+    let reader = SnapshotReader::new_in_memory(ChainConfig::local_testnet(), state);
+
+    // when
+    let db = SnapshotImporter::import_to_memory(Block::default(), reader)
+        .await
+        .unwrap();
+
+    // then
+    let imported = db
+        .on_chain()
+        .entries::<Coins>(None, IterDirection::Forward)
+        .count();
+    assert_eq!(imported, coins.len());
+}
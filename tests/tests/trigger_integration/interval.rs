@@ -19,6 +19,7 @@ use fuel_core_types::{
     fuel_tx::TransactionBuilder,
     secrecy::Secret,
 };
+use futures::StreamExt;
 use rand::{
     rngs::StdRng,
     Rng,
@@ -195,3 +196,136 @@ async fn poa_interval_produces_nonempty_blocks_at_correct_rate() {
 
     assert_eq!(txs_len, coinbase_tx_count + tx_count);
 }
+
+#[tokio::test(start_paused = true)]
+async fn poa_interval_block_production_events_have_contiguous_heights() {
+    let events_to_collect = 5;
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval {
+        block_time: Duration::new(2, 0),
+    };
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let events: Vec<_> = srv
+        .subscribe_block_production()
+        .take(events_to_collect)
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), events_to_collect);
+    for pair in events.windows(2) {
+        assert_eq!(pair[1].height, pair[0].height.succ().unwrap());
+    }
+    // Empty blocks contain only the `Mint` transaction.
+    assert!(events.iter().all(|event| event.tx_count == 1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_interval_is_reported_by_block_production_config() {
+    // given
+    let block_time = Duration::from_secs(30);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval { block_time };
+
+    // when
+    let srv = FuelService::new_node(config).await.unwrap();
+    let info = srv.block_production_config();
+
+    // then
+    assert_eq!(info.trigger, Trigger::Interval { block_time });
+    assert!(info.enabled);
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_aligned_interval_produces_blocks_on_aligned_timestamps() {
+    let block_time_seconds = 5;
+    let blocks_to_produce = 4;
+    let mut config = Config::local_node();
+    config.block_production = Trigger::AlignedInterval {
+        block_time: Duration::from_secs(block_time_seconds),
+    };
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let _: Vec<_> = srv
+        .subscribe_block_production()
+        .take(blocks_to_produce)
+        .collect()
+        .await;
+
+    let blocks = client
+        .blocks(PaginationRequest {
+            cursor: None,
+            results: 1024,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .expect("blocks request failed")
+        .results;
+
+    let produced_blocks = blocks.iter().skip(1).collect::<Vec<_>>();
+    assert!(produced_blocks.len() >= blocks_to_produce);
+    for block in produced_blocks {
+        let timestamp = block.header.time.to_unix();
+        assert_eq!(
+            timestamp % i64::try_from(block_time_seconds).unwrap(),
+            0,
+            "Block {} has unaligned timestamp {timestamp}",
+            block.header.height
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_interval_skips_empty_blocks_until_a_transaction_arrives() {
+    // given
+    let block_time = Duration::from_secs(2);
+    let mut rng = StdRng::seed_from_u64(10);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval { block_time };
+    config.skip_empty_blocks = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut block_production = srv.subscribe_block_production();
+    let initial_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    // when
+    tokio::time::sleep(block_time * 10).await;
+
+    // then
+    let height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    assert_eq!(height, initial_height);
+
+    // when
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+    let event = block_production.next().await.unwrap();
+
+    // then
+    assert_eq!(u32::from(event.height), initial_height + 1);
+    // The transaction together with the `Mint` transaction.
+    assert_eq!(event.tx_count, 2);
+}
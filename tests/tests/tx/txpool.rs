@@ -4,6 +4,7 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core::service::config::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
@@ -68,3 +69,69 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn test_block_gas_limit_spreads_txs_across_multiple_blocks() {
+    const SCRIPT_GAS_LIMIT: u64 = 1_000_000;
+    const TX_COUNT: usize = 10;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.trigger = Trigger::Never;
+    // given
+    // each tx needs a bit more than `SCRIPT_GAS_LIMIT`, so only two fit into a block
+    test_builder.test_block_gas_limit = Some(SCRIPT_GAS_LIMIT * 5 / 2);
+    let transactions = (1..=TX_COUNT as u64)
+        .map(|i| {
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(SCRIPT_GAS_LIMIT)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000 + i,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::Change {
+                amount: 0,
+                asset_id: Default::default(),
+                to: rng.gen(),
+            })
+            .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+    let txs = transactions
+        .into_iter()
+        .map(|script| Arc::new(fuel_tx::Transaction::from(script)))
+        .collect::<Vec<_>>();
+    srv.shared.txpool_shared_state.insert(txs).await;
+
+    // when
+    let blocks_needed = TX_COUNT / 2;
+    client
+        .produce_blocks(blocks_needed as u32, None)
+        .await
+        .unwrap();
+
+    // then
+    let mut included = 0;
+    for height in 1..=blocks_needed as u32 {
+        let block = client
+            .block_by_height(height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        // every block also carries the mint transaction
+        let block_txs = block.transactions.len() - 1;
+        assert_eq!(
+            block_txs, 2,
+            "block {height} should be filled up to the limit"
+        );
+        included += block_txs;
+    }
+    assert_eq!(included, TX_COUNT);
+}